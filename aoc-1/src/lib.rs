@@ -0,0 +1,281 @@
+use aoc_common::parsers::{parse_all, parse_lines, signed};
+use aoc_common::{Answer, ParamError, Params, ParseError, Solution, SolveError};
+use std::collections::HashSet;
+use std::convert::TryFrom;
+use std::error::Error;
+use std::fmt;
+use std::io::{self, BufRead};
+
+#[derive(Default)]
+pub struct Day1 {
+    /// Allows changes beyond `i32`, with frequencies summed without overflowing. Otherwise
+    /// frequencies leaving `i32` are an error.
+    pub wide: bool,
+}
+
+impl Solution for Day1 {
+    type Input = Vec<i64>;
+
+    fn configure(&mut self, params: &mut Params) -> Result<(), ParamError> {
+        params.update("wide", &mut self.wide)
+    }
+
+    fn parse(&self, contents: &str) -> Result<Vec<i64>, ParseError> {
+        if self.wide {
+            parse_lines(contents, signed)
+        } else {
+            let values = parse_lines(contents, signed::<i32>)?;
+            Ok(values.into_iter().map(i64::from).collect())
+        }
+    }
+
+    fn part1(&self, values: &Vec<i64>) -> Result<Answer, SolveError> {
+        self.check_range(values)?;
+        Ok(values
+            .iter()
+            .map(|&value| i128::from(value))
+            .sum::<i128>()
+            .into())
+    }
+
+    fn part2(&self, values: &Vec<i64>) -> Result<Answer, SolveError> {
+        self.check_range(values)?;
+        first_repeated(values)
+            .map(Answer::from)
+            .ok_or_else(|| SolveError::new("no frequency is ever reached twice"))
+    }
+}
+
+impl Day1 {
+    /// Fails if a frequency of the first pass over the values leaves `i32`, unless in wide mode.
+    fn check_range(&self, values: &[i64]) -> Result<(), SolveError> {
+        if self.wide {
+            return Ok(());
+        }
+        let mut freq = 0i32;
+        for (line, &value) in values.iter().enumerate() {
+            freq = narrow_step(freq, value).ok_or_else(|| overflow(line + 1))?;
+        }
+        Ok(())
+    }
+
+    /// Starts calibrating from a stream with the same range checks as solving the puzzle does.
+    pub fn calibration(&self, find_repeated: bool) -> Calibration {
+        if self.wide {
+            Calibration::wide(find_repeated)
+        } else {
+            Calibration::new(find_repeated)
+        }
+    }
+}
+
+/// The next frequency, if both the change and the frequency fit in `i32`.
+fn narrow_step(freq: i32, change: i64) -> Option<i32> {
+    i32::try_from(change)
+        .ok()
+        .and_then(|change| freq.checked_add(change))
+}
+
+fn overflow(line: usize) -> SolveError {
+    SolveError::new(format!(
+        "the frequency overflows i32 at line {}; set the `wide` parameter to allow larger \
+         frequencies",
+        line
+    ))
+}
+
+/// The first frequency reached twice while applying `values` over and over, if there is one.
+///
+/// Every pass over the values shifts the frequencies of the first pass by the drift, their sum. So
+/// a frequency is reached again only by shifting another one of the first pass that leaves the same
+/// residue modulo the drift, or by the first pass reaching it twice. Sorting the frequencies by
+/// residue and value puts the closest such pairs next to each other, and the pair that meets in the
+/// fewest steps wins.
+///
+/// The frequencies are summed as `i128`, which no sum of fewer than 2^64 `i64` values overflows.
+fn first_repeated(values: &[i64]) -> Option<i128> {
+    let drift = values.iter().map(|&value| i128::from(value)).sum::<i128>();
+    // Without a drift every frequency repeats after one pass; grouping them all together still
+    // finds the ones repeating within the first pass.
+    let modulus = if drift == 0 { 1 } else { drift.abs() };
+
+    let mut sorted = Vec::with_capacity(values.len());
+    let mut current_freq = 0i128;
+    for (step, &value) in values.iter().enumerate() {
+        sorted.push((current_freq.rem_euclid(modulus), current_freq, step as i128));
+        current_freq += i128::from(value);
+    }
+    sorted.sort_unstable();
+
+    let pass_len = values.len() as i128;
+    let next_pass = if drift == 0 {
+        Some((pass_len, 0))
+    } else {
+        None
+    };
+    sorted
+        .windows(2)
+        .filter_map(|pair| {
+            let ((residue1, freq1, step1), (residue2, freq2, step2)) = (pair[0], pair[1]);
+            if residue1 != residue2 {
+                None
+            } else if freq1 == freq2 {
+                Some((step2, freq2))
+            } else if drift == 0 {
+                None
+            } else {
+                // Shifting by the drift takes one of the two frequencies to the other.
+                let ((from, from_step), to) = if drift > 0 {
+                    ((freq1, step1), freq2)
+                } else {
+                    ((freq2, step2), freq1)
+                };
+                let passes = (to - from) / drift;
+                Some((passes * pass_len + from_step, to))
+            }
+        })
+        .chain(next_pass)
+        .min()
+        .map(|(_, freq)| freq)
+}
+
+/// The state of calibrating the device from changes arriving one at a time.
+///
+/// Frequencies are summed as `i128`, so that no realistic stream of `i64` changes overflows them.
+/// Unless in wide mode, like `Day1`, changes and frequencies have to fit in `i32`.
+#[derive(Clone, Debug)]
+pub struct Calibration {
+    wide: bool,
+    /// The number of changes applied so far.
+    changes: usize,
+    frequency: i128,
+    /// Every frequency reached so far, while looking for the first one reached twice.
+    encountered: Option<HashSet<i128>>,
+    first_repeated: Option<i128>,
+}
+
+impl Calibration {
+    /// Starts calibrating from 0. Looking for the first repeated frequency takes memory for every
+    /// frequency reached until then, so it's only done if `find_repeated` is set.
+    pub fn new(find_repeated: bool) -> Self {
+        let mut encountered = HashSet::new();
+        let _ = encountered.insert(0);
+        Self {
+            wide: false,
+            changes: 0,
+            frequency: 0,
+            encountered: if find_repeated {
+                Some(encountered)
+            } else {
+                None
+            },
+            first_repeated: None,
+        }
+    }
+
+    /// Starts calibrating from 0 like `new`, but with changes and frequencies beyond `i32` allowed.
+    pub fn wide(find_repeated: bool) -> Self {
+        Self {
+            wide: true,
+            ..Self::new(find_repeated)
+        }
+    }
+
+    pub fn is_wide(&self) -> bool {
+        self.wide
+    }
+
+    /// Applies a change, returning the new frequency if it's the first one reached twice. Fails,
+    /// leaving the frequency as it was, if the change or the frequency leaves `i32` unless in wide
+    /// mode.
+    pub fn apply(&mut self, change: i64) -> Result<Option<i128>, SolveError> {
+        if !self.wide {
+            // The frequency has always fitted in `i32` so far.
+            narrow_step(self.frequency as i32, change).ok_or_else(|| overflow(self.changes + 1))?;
+        }
+        self.changes += 1;
+        self.frequency += i128::from(change);
+        let encountered = match self.encountered.as_mut() {
+            Some(encountered) => encountered,
+            None => return Ok(None),
+        };
+        if encountered.insert(self.frequency) {
+            return Ok(None);
+        }
+        self.encountered = None;
+        self.first_repeated = Some(self.frequency);
+        Ok(self.first_repeated)
+    }
+
+    pub fn frequency(&self) -> i128 {
+        self.frequency
+    }
+
+    /// The first frequency reached twice so far, if it's being looked for.
+    pub fn first_repeated(&self) -> Option<i128> {
+        self.first_repeated
+    }
+}
+
+/// An error while calibrating from a stream of changes.
+#[derive(Debug)]
+pub enum StreamError {
+    Io(io::Error),
+    Parse(ParseError),
+    /// A frequency left `i32` outside wide mode.
+    Overflow(SolveError),
+}
+
+impl fmt::Display for StreamError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            StreamError::Io(err) => write!(f, "couldn't read the changes: {}", err),
+            StreamError::Parse(err) => write!(f, "{}", err),
+            StreamError::Overflow(err) => write!(f, "{}", err),
+        }
+    }
+}
+
+impl Error for StreamError {}
+
+impl From<io::Error> for StreamError {
+    fn from(err: io::Error) -> Self {
+        StreamError::Io(err)
+    }
+}
+
+/// Applies the changes read from `reader`, one per line, until it ends. `on_repeat` is called as
+/// soon as the first frequency is reached twice, unlike the puzzle's part 2 only within a single
+/// pass over the changes, as a stream can't be replayed.
+pub fn calibrate<R, F>(
+    mut reader: R,
+    calibration: &mut Calibration,
+    mut on_repeat: F,
+) -> Result<(), StreamError>
+where
+    R: BufRead,
+    F: FnMut(i128),
+{
+    let mut line = String::new();
+    let mut line_number = 0;
+    loop {
+        line.clear();
+        if reader.read_line(&mut line)? == 0 {
+            return Ok(());
+        }
+        line_number += 1;
+        let line = line.trim_end_matches(&['\n', '\r'][..]);
+        let change = if calibration.is_wide() {
+            parse_all(line, signed)
+        } else {
+            parse_all(line, signed::<i32>).map(i64::from)
+        };
+        let change = change.map_err(|mut err| {
+            err.line = line_number;
+            StreamError::Parse(err)
+        })?;
+        if let Some(freq) = calibration.apply(change).map_err(StreamError::Overflow)? {
+            on_repeat(freq);
+        }
+    }
+}
@@ -0,0 +1,111 @@
+use aoc_1::Day1;
+use aoc_common::{Answer, Rng, Solution, SolveError};
+use std::collections::HashSet;
+
+fn try_first_repeated(input: &str) -> Result<Answer, SolveError> {
+    let day = Day1::default();
+    day.part2(&day.parse(input).unwrap())
+}
+
+fn resulting(input: &str) -> Answer {
+    let day = Day1::default();
+    day.part1(&day.parse(input).unwrap()).unwrap()
+}
+
+fn first_repeated(input: &str) -> Answer {
+    try_first_repeated(input).unwrap()
+}
+
+#[test]
+fn resulting_frequency() {
+    assert_eq!(resulting("+1\n-2\n+3\n+1\n"), Answer::Number(3));
+    assert_eq!(resulting("+1\n+1\n+1\n"), Answer::Number(3));
+    assert_eq!(resulting("+1\n+1\n-2\n"), Answer::Number(0));
+    assert_eq!(resulting("-1\n-2\n-3\n"), Answer::Number(-6));
+}
+
+#[test]
+fn first_repeated_frequency() {
+    assert_eq!(first_repeated("+1\n-2\n+3\n+1\n"), Answer::Number(2));
+    assert_eq!(first_repeated("+1\n-1\n"), Answer::Number(0));
+    assert_eq!(first_repeated("+3\n+3\n+4\n-2\n-4\n"), Answer::Number(10));
+    assert_eq!(first_repeated("-6\n+3\n+8\n+5\n-6\n"), Answer::Number(5));
+    assert_eq!(first_repeated("+7\n+7\n-2\n-7\n-4\n"), Answer::Number(14));
+}
+
+#[test]
+fn no_repeated_frequency() {
+    for input in ["+1\n+1\n", "+3\n-1\n", "-7\n+2\n"] {
+        assert!(try_first_repeated(input).is_err());
+    }
+}
+
+/// Applies the changes over and over until a frequency repeats.
+fn brute_force(changes: &[i32]) -> i64 {
+    let mut encountered = HashSet::new();
+    let mut freq = 0i64;
+    for &change in changes.iter().cycle() {
+        if !encountered.insert(freq) {
+            break;
+        }
+        freq += i64::from(change);
+    }
+    freq
+}
+
+#[test]
+fn matches_brute_force() {
+    let mut rng = Rng::new(12345);
+    for _ in 0..500 {
+        let len = rng.range(1, 13) as usize;
+        let changes: Vec<i32> = (0..len).map(|_| rng.range(-20, 21) as i32).collect();
+        let input: String = changes.iter().map(|c| format!("{:+}\n", c)).collect();
+        let repeated = try_first_repeated(&input);
+        if let Ok(answer) = repeated {
+            assert_eq!(
+                answer,
+                Answer::Number(brute_force(&changes)),
+                "{:?}",
+                changes
+            );
+        }
+    }
+}
+
+#[test]
+fn overflow() {
+    let input = "+2147483647\n+1\n-5\n";
+    let day = Day1::default();
+    let changes = day.parse(input).unwrap();
+    assert!(day.part1(&changes).is_err());
+    assert!(day.part2(&changes).is_err());
+    assert!(day.parse("+2147483648\n").is_err());
+}
+
+#[test]
+fn minimum_changes() {
+    let day = Day1::default();
+    let changes = day.parse("-2147483648\n").unwrap();
+    assert_eq!(day.part1(&changes).unwrap(), Answer::Number(-2147483648));
+    assert!(day.parse("-2147483649\n").is_err());
+
+    let day = Day1 { wide: true };
+    let changes = day.parse("-9223372036854775808\n").unwrap();
+    assert_eq!(day.part1(&changes).unwrap(), Answer::Number(i64::MIN));
+}
+
+#[test]
+fn wide() {
+    let day = Day1 { wide: true };
+    let changes = day.parse("+2147483647\n+1\n-5\n").unwrap();
+    assert_eq!(day.part1(&changes).unwrap(), Answer::Number(2147483643));
+    let changes = day
+        .parse("+9223372036854775807\n+9223372036854775807\n")
+        .unwrap();
+    assert_eq!(
+        day.part1(&changes).unwrap(),
+        Answer::from("18446744073709551614")
+    );
+    let changes = day.parse("+5000000000\n-1\n-5000000000\n").unwrap();
+    assert_eq!(day.part2(&changes).unwrap(), Answer::Number(4999999999));
+}
@@ -0,0 +1,93 @@
+use aoc_1::{calibrate, Calibration, Day1, StreamError};
+use aoc_common::{Params, Solution};
+use std::io::Cursor;
+
+fn stream(input: &str) -> (Calibration, Vec<i128>) {
+    let mut calibration = Calibration::new(true);
+    let mut repeats = Vec::new();
+    calibrate(Cursor::new(input), &mut calibration, |freq| {
+        repeats.push(freq)
+    })
+    .unwrap();
+    (calibration, repeats)
+}
+
+#[test]
+fn running_sum() {
+    let (calibration, _) = stream("+1\n-2\n+3\n+1\n");
+    assert_eq!(calibration.frequency(), 3);
+    let (calibration, _) = stream("-1\r\n-2\r\n-3");
+    assert_eq!(calibration.frequency(), -6);
+}
+
+#[test]
+fn first_repeat_reported_once() {
+    let (calibration, repeats) = stream("+3\n+3\n+4\n-2\n-4\n+3\n+3\n+4\n-2\n-4\n");
+    assert_eq!(repeats, vec![10]);
+    assert_eq!(calibration.first_repeated(), Some(10));
+    assert_eq!(calibration.frequency(), 8);
+}
+
+#[test]
+fn no_repeat_within_stream() {
+    let (calibration, repeats) = stream("+1\n-2\n+3\n+1\n");
+    assert!(repeats.is_empty());
+    assert_eq!(calibration.first_repeated(), None);
+}
+
+#[test]
+fn sum_only() {
+    let mut calibration = Calibration::new(false);
+    let mut repeats = 0;
+    calibrate(Cursor::new("+1\n-1\n"), &mut calibration, |_| repeats += 1).unwrap();
+    assert_eq!(repeats, 0);
+    assert_eq!(calibration.frequency(), 0);
+}
+
+#[test]
+fn parse_error_on_later_line() {
+    let mut calibration = Calibration::new(true);
+    let err = calibrate(Cursor::new("+1\n+2\n3\n"), &mut calibration, |_| ()).unwrap_err();
+    match err {
+        StreamError::Parse(err) => assert_eq!((err.line, err.column), (3, 1)),
+        err => panic!("unexpected error: {}", err),
+    }
+    assert_eq!(calibration.frequency(), 3);
+}
+
+#[test]
+fn overflow_like_batch_mode() {
+    let input = "+2147483000\n+647\n+1\n-5\n";
+    let mut calibration = Day1::default().calibration(true);
+    let err = calibrate(Cursor::new(input), &mut calibration, |_| ()).unwrap_err();
+    let batch = Day1::default();
+    let batch_err = batch.part1(&batch.parse(input).unwrap()).unwrap_err();
+    match err {
+        StreamError::Overflow(err) => assert_eq!(err, batch_err),
+        err => panic!("unexpected error: {}", err),
+    }
+    assert_eq!(calibration.frequency(), 2147483647);
+
+    let mut calibration = Calibration::new(false);
+    let err = calibrate(Cursor::new("+1\n+5000000000\n"), &mut calibration, |_| ()).unwrap_err();
+    match err {
+        StreamError::Parse(err) => assert_eq!((err.line, err.column), (2, 2)),
+        err => panic!("unexpected error: {}", err),
+    }
+}
+
+#[test]
+fn wide_stream() {
+    let mut day = Day1::default();
+    let mut params = Params::new();
+    params.set("wide", "true");
+    day.configure(&mut params).unwrap();
+    let mut calibration = day.calibration(false);
+    calibrate(
+        Cursor::new("+2147483647\n+1\n+5000000000\n"),
+        &mut calibration,
+        |_| (),
+    )
+    .unwrap();
+    assert_eq!(calibration.frequency(), 7147483648);
+}
@@ -1,24 +1,10 @@
-use nom::types::CompleteStr;
-use nom::*;
-use std::fs::File;
-use std::io::Read;
-use std::isize;
-
-named!(number <CompleteStr, isize>, map!(digit, |x| x.parse().unwrap()));
-
-named!(sign <CompleteStr, isize>, alt!(
-    char!(' ') => { |_| 1 } |
-    char!('-') => { |_| -1 }
-));
-
-named!(signed <CompleteStr, isize>, do_parse!(
-    sign: sign >>
-    num: number >>
-    (sign * num)
-));
+use aoc_common::parsers::{padded_signed, parse_lines, token, ParseResult};
+use aoc_common::{Answer, ParseError, Solution, SolveError};
+use nom::sequence::{delimited, separated_pair};
+use nom::Parser;
 
 #[derive(Clone, Copy, Debug)]
-struct Light {
+pub struct Light {
     pos: (isize, isize),
     vel: (isize, isize),
 }
@@ -35,21 +21,18 @@ impl Light {
     }
 }
 
-named!(light <CompleteStr, Light>, do_parse!(
-    tag!("position=<") >>
-    pos_x: signed >>
-    tag!(", ") >>
-    pos_y: signed >>
-    tag!("> velocity=<") >>
-    vel_x: signed >>
-    tag!(", ") >>
-    vel_y: signed >>
-    tag!(">") >>
-    multispace >>
-    (Light { pos: (pos_x, pos_y), vel: (vel_x, vel_y) })
-));
-
-named!(parse <CompleteStr, Vec<Light>>, many1!(light));
+fn vector(input: &str) -> ParseResult<'_, (isize, isize)> {
+    separated_pair(padded_signed, token(","), padded_signed).parse(input)
+}
+
+fn light(input: &str) -> ParseResult<'_, Light> {
+    (
+        delimited(token("position=<"), vector, token(">")),
+        delimited(token(" velocity=<"), vector, token(">")),
+    )
+        .map(|(pos, vel)| Light { pos, vel })
+        .parse(input)
+}
 
 struct Sky {
     lights: Vec<Light>,
@@ -145,7 +128,7 @@ impl Sky {
         (self.bbox.max_x - self.bbox.min_x + 1) * (self.bbox.max_y - self.bbox.min_y + 1)
     }
 
-    fn print(&self) {
+    fn render(&self) -> String {
         let row_len = self.bbox.max_x - self.bbox.min_x + 1;
         let mut lines = (self.bbox.min_y..=self.bbox.max_y)
             .map(|_| vec![b' '; row_len as usize])
@@ -157,21 +140,16 @@ impl Sky {
             lines[row][col] = b'#';
         }
 
-        for line in lines {
-            let s = String::from_utf8(line).unwrap();
-            println!("{}", s);
-        }
+        lines
+            .into_iter()
+            .map(|line| String::from_utf8(line).unwrap())
+            .collect::<Vec<_>>()
+            .join("\n")
     }
 }
 
-fn main() {
-    let mut file = File::open("input").unwrap();
-    let mut content = String::new();
-    let _ = file.read_to_string(&mut content);
-
-    let data = parse(CompleteStr(&content)).unwrap().1;
-
-    let mut sky = Sky::new(data);
+fn find_message(lights: &[Light]) -> (Sky, usize) {
+    let mut sky = Sky::new(lights.to_vec());
     let mut bbox_area = sky.bbox_area();
     let mut seconds = 0;
 
@@ -185,8 +163,23 @@ fn main() {
         seconds += 1;
     }
 
-    println!("Part 1:");
-    sky.print();
+    (sky, seconds)
+}
+
+pub struct Day10;
+
+impl Solution for Day10 {
+    type Input = Vec<Light>;
+
+    fn parse(&self, content: &str) -> Result<Vec<Light>, ParseError> {
+        parse_lines(content, light)
+    }
+
+    fn part1(&self, lights: &Vec<Light>) -> Result<Answer, SolveError> {
+        Ok(find_message(lights).0.render().into())
+    }
 
-    println!("Part 2: {}", seconds);
+    fn part2(&self, lights: &Vec<Light>) -> Result<Answer, SolveError> {
+        Ok(find_message(lights).1.into())
+    }
 }
@@ -0,0 +1,140 @@
+use std::mem;
+
+/// How far apart two box IDs are.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Metric {
+    /// The number of positions holding different letters. It's only defined for IDs of the same
+    /// length, so IDs of different lengths are never within any Hamming distance of each other.
+    Hamming,
+    /// The number of letters to insert, remove or replace to turn one ID into the other.
+    Levenshtein,
+}
+
+impl Metric {
+    /// The distance between two IDs, if it's defined for them.
+    pub fn distance(self, id1: &str, id2: &str) -> Option<usize> {
+        self.distance_within(id1, id2, usize::MAX)
+    }
+
+    /// The distance between two IDs, if it's defined and at most `limit`.
+    pub fn distance_within(self, id1: &str, id2: &str, limit: usize) -> Option<usize> {
+        let id1: Vec<char> = id1.chars().collect();
+        let id2: Vec<char> = id2.chars().collect();
+        self.between(&id1, &id2, limit)
+    }
+
+    fn between(self, id1: &[char], id2: &[char], limit: usize) -> Option<usize> {
+        match self {
+            Metric::Hamming => hamming(id1, id2, limit),
+            Metric::Levenshtein => levenshtein(id1, id2, limit),
+        }
+    }
+}
+
+fn hamming(id1: &[char], id2: &[char], limit: usize) -> Option<usize> {
+    if id1.len() != id2.len() {
+        return None;
+    }
+    let mut distance = 0;
+    for (a, b) in id1.iter().zip(id2) {
+        if a != b {
+            distance += 1;
+            if distance > limit {
+                return None;
+            }
+        }
+    }
+    Some(distance)
+}
+
+/// The classic dynamic programming over prefixes of both IDs, a row at a time, giving up as soon
+/// as a whole row exceeds `limit`.
+fn levenshtein(id1: &[char], id2: &[char], limit: usize) -> Option<usize> {
+    // Every letter one ID has more than the other has to be inserted.
+    if id1.len().max(id2.len()) - id1.len().min(id2.len()) > limit {
+        return None;
+    }
+    let mut previous: Vec<usize> = (0..=id2.len()).collect();
+    let mut current = vec![0; id2.len() + 1];
+    for (i, a) in id1.iter().enumerate() {
+        current[0] = i + 1;
+        for (j, b) in id2.iter().enumerate() {
+            let replace = previous[j] + if a == b { 0 } else { 1 };
+            current[j + 1] = replace.min(previous[j + 1] + 1).min(current[j] + 1);
+        }
+        if current.iter().all(|&distance| distance > limit) {
+            return None;
+        }
+        mem::swap(&mut previous, &mut current);
+    }
+    Some(previous[id2.len()]).filter(|&distance| distance <= limit)
+}
+
+/// Two IDs of an inventory, by their indices, and the distance between them.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Pair {
+    pub first: usize,
+    pub second: usize,
+    pub distance: usize,
+}
+
+/// A collection of box IDs that can be searched for similar ones.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct Inventory {
+    ids: Vec<String>,
+}
+
+impl Inventory {
+    pub fn new<I, S>(ids: I) -> Self
+    where
+        I: IntoIterator<Item = S>,
+        S: Into<String>,
+    {
+        Self {
+            ids: ids.into_iter().map(Into::into).collect(),
+        }
+    }
+
+    pub fn ids(&self) -> &[String] {
+        &self.ids
+    }
+
+    /// All pairs of different IDs at most `max_distance` apart, each listed once with the earlier
+    /// ID first, in the order of the IDs.
+    pub fn pairs_within(&self, metric: Metric, max_distance: usize) -> Vec<Pair> {
+        let ids: Vec<Vec<char>> = self.ids.iter().map(|id| id.chars().collect()).collect();
+        let mut pairs = Vec::new();
+        for (first, id1) in ids.iter().enumerate() {
+            for (second, id2) in ids.iter().enumerate().skip(first + 1) {
+                if let Some(distance) = metric.between(id1, id2, max_distance) {
+                    pairs.push(Pair {
+                        first,
+                        second,
+                        distance,
+                    });
+                }
+            }
+        }
+        pairs
+    }
+
+    /// The ID closest to `query` and its distance, the earliest one if several are as close. There
+    /// is none if no ID has a distance to the query, like when the inventory is empty.
+    pub fn nearest(&self, metric: Metric, query: &str) -> Option<(&str, usize)> {
+        let query: Vec<char> = query.chars().collect();
+        let mut nearest: Option<(&str, usize)> = None;
+        for id in &self.ids {
+            // Only a strictly closer ID can replace the nearest one so far.
+            let limit = match nearest {
+                Some((_, 0)) => break,
+                Some((_, distance)) => distance - 1,
+                None => usize::MAX,
+            };
+            let id_chars: Vec<char> = id.chars().collect();
+            if let Some(distance) = metric.between(&query, &id_chars, limit) {
+                nearest = Some((id, distance));
+            }
+        }
+        nearest
+    }
+}
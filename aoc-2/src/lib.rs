@@ -0,0 +1,194 @@
+mod inventory;
+
+pub use crate::inventory::{Inventory, Metric, Pair};
+
+use aoc_common::{Answer, ParseError, Solution, SolveError};
+#[cfg(feature = "rayon")]
+use rayon::prelude::*;
+use std::collections::hash_map::{Entry, HashMap};
+
+/// The IDs containing some letter exactly a given number of times.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct MultiplicityCount {
+    pub multiplicity: usize,
+    /// The indices of the IDs, in order.
+    pub ids: Vec<usize>,
+}
+
+impl MultiplicityCount {
+    pub fn count(&self) -> usize {
+        self.ids.len()
+    }
+}
+
+/// A checksum, along with the counts it was combined from.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ChecksumReport {
+    pub checksum: usize,
+    /// One count for every multiplicity, in the order they were given.
+    pub counts: Vec<MultiplicityCount>,
+}
+
+/// Counts the IDs containing some letter exactly so many times, for each of several
+/// multiplicities, and combines the counts into a checksum.
+pub struct Checksum<F> {
+    multiplicities: Vec<usize>,
+    combine: F,
+}
+
+impl Checksum<fn(&[usize]) -> usize> {
+    /// The product of the counts, which for 2 and 3 is the puzzle's checksum.
+    pub fn product<I: IntoIterator<Item = usize>>(multiplicities: I) -> Self {
+        Self::new(multiplicities, |counts| counts.iter().product())
+    }
+}
+
+impl<F: Fn(&[usize]) -> usize> Checksum<F> {
+    /// `combine` is given the counts in the order of `multiplicities`.
+    pub fn new<I: IntoIterator<Item = usize>>(multiplicities: I, combine: F) -> Self {
+        Self {
+            multiplicities: multiplicities.into_iter().collect(),
+            combine,
+        }
+    }
+
+    pub fn compute<S: AsRef<str>>(&self, ids: &[S]) -> ChecksumReport {
+        self.report(self.count(ids, 0))
+    }
+
+    /// Like `compute`, but counts chunks of the IDs on all cores.
+    #[cfg(feature = "rayon")]
+    pub fn compute_parallel<S: AsRef<str> + Sync>(&self, ids: &[S]) -> ChecksumReport
+    where
+        F: Sync,
+    {
+        const CHUNK_LEN: usize = 4096;
+        let chunks: Vec<_> = ids
+            .par_chunks(CHUNK_LEN)
+            .enumerate()
+            .map(|(i, chunk)| self.count(chunk, i * CHUNK_LEN))
+            .collect();
+        let mut counts = self.no_counts();
+        for chunk in chunks {
+            for (count, chunk_count) in counts.iter_mut().zip(chunk) {
+                count.ids.extend(chunk_count.ids);
+            }
+        }
+        self.report(counts)
+    }
+
+    fn no_counts(&self) -> Vec<MultiplicityCount> {
+        self.multiplicities
+            .iter()
+            .map(|&multiplicity| MultiplicityCount {
+                multiplicity,
+                ids: Vec::new(),
+            })
+            .collect()
+    }
+
+    /// Counts the IDs for every multiplicity, numbering them from `first_index`.
+    fn count<S: AsRef<str>>(&self, ids: &[S], first_index: usize) -> Vec<MultiplicityCount> {
+        let mut counts = self.no_counts();
+        for (index, id) in ids.iter().enumerate() {
+            let id = id.as_ref();
+            if id.is_ascii() {
+                record(&mut counts, first_index + index, ascii_multiplicities(id));
+            } else {
+                record(&mut counts, first_index + index, unicode_multiplicities(id));
+            }
+        }
+        counts
+    }
+
+    fn report(&self, counts: Vec<MultiplicityCount>) -> ChecksumReport {
+        let numbers: Vec<usize> = counts.iter().map(MultiplicityCount::count).collect();
+        ChecksumReport {
+            checksum: (self.combine)(&numbers),
+            counts,
+        }
+    }
+}
+
+fn record<H: Fn(usize) -> bool>(counts: &mut [MultiplicityCount], id: usize, has_multiplicity: H) {
+    for count in counts {
+        if has_multiplicity(count.multiplicity) {
+            count.ids.push(id);
+        }
+    }
+}
+
+/// Whether some letter of an ASCII `id` occurs exactly so many times, counted without allocating.
+fn ascii_multiplicities(id: &str) -> impl Fn(usize) -> bool {
+    let mut letters = [0u32; 128];
+    for &byte in id.as_bytes() {
+        letters[byte as usize] += 1;
+    }
+    move |multiplicity| multiplicity > 0 && letters.iter().any(|&n| n as usize == multiplicity)
+}
+
+/// Whether some letter of `id` occurs exactly so many times.
+fn unicode_multiplicities(id: &str) -> impl Fn(usize) -> bool {
+    let mut letters = HashMap::new();
+    for letter in id.chars() {
+        *letters.entry(letter).or_insert(0) += 1;
+    }
+    move |multiplicity| letters.values().any(|&n| n == multiplicity)
+}
+
+/// The letters two IDs differing in exactly one position have in common, if there are such IDs.
+///
+/// For every position in turn, the IDs are recorded with the letter there masked out, along with
+/// the masked letter. Another ID with the same masked form but a different letter differs in that
+/// position only, so that the pair is found in time linear in the number of IDs.
+fn common_letters(ids: &[String]) -> Option<String> {
+    let mut letters: Vec<_> = ids.iter().map(|id| id.char_indices()).collect();
+    let mut masked: HashMap<(&str, &str), char> = HashMap::with_capacity(ids.len());
+    loop {
+        masked.clear();
+        for (id, letters) in ids.iter().zip(&mut letters) {
+            let (i, letter) = match letters.next() {
+                Some(next) => next,
+                None => continue,
+            };
+            let (before, after) = (&id[..i], &id[i + letter.len_utf8()..]);
+            match masked.entry((before, after)) {
+                Entry::Occupied(entry) if *entry.get() != letter => {
+                    return Some(format!("{}{}", before, after));
+                }
+                Entry::Occupied(_) => {}
+                Entry::Vacant(entry) => {
+                    let _ = entry.insert(letter);
+                }
+            }
+        }
+        if masked.is_empty() {
+            return None;
+        }
+    }
+}
+
+pub struct Day2;
+
+impl Solution for Day2 {
+    type Input = Vec<String>;
+
+    fn parse(&self, contents: &str) -> Result<Vec<String>, ParseError> {
+        Ok(contents.lines().map(str::to_owned).collect())
+    }
+
+    fn part1(&self, ids: &Vec<String>) -> Result<Answer, SolveError> {
+        let checksum = Checksum::product([2, 3]);
+        #[cfg(not(feature = "rayon"))]
+        let report = checksum.compute(ids);
+        #[cfg(feature = "rayon")]
+        let report = checksum.compute_parallel(ids);
+        Ok(report.checksum.into())
+    }
+
+    fn part2(&self, ids: &Vec<String>) -> Result<Answer, SolveError> {
+        common_letters(ids)
+            .map(Answer::from)
+            .ok_or_else(|| SolveError::new("no two IDs differ by exactly one character"))
+    }
+}
@@ -0,0 +1,82 @@
+use aoc_2::{Checksum, MultiplicityCount};
+use std::cell::Cell;
+
+const IDS: &[&str] = &[
+    "abcdef", "bababc", "abbcde", "abcccd", "aabcdd", "abcdee", "ababab",
+];
+
+#[test]
+fn puzzle_checksum() {
+    let report = Checksum::product(vec![2, 3]).compute(IDS);
+    assert_eq!(report.checksum, 12);
+    assert_eq!(
+        report.counts,
+        vec![
+            MultiplicityCount {
+                multiplicity: 2,
+                ids: vec![1, 2, 4, 5],
+            },
+            MultiplicityCount {
+                multiplicity: 3,
+                ids: vec![1, 3, 6],
+            },
+        ]
+    );
+}
+
+#[test]
+fn other_multiplicities() {
+    let ids = ["aaaa", "aaab", "aabb", "abcd"];
+    let report = Checksum::new(vec![1, 4, 2], |counts| counts.iter().sum()).compute(&ids);
+    let counts: Vec<_> = report.counts.iter().map(|count| count.count()).collect();
+    assert_eq!(counts, vec![2, 1, 1]);
+    assert_eq!(report.counts[0].ids, vec![1, 3]);
+    assert_eq!(report.checksum, 4);
+}
+
+#[test]
+fn no_ids() {
+    let report = Checksum::product(vec![2, 3]).compute::<&str>(&[]);
+    assert_eq!(report.checksum, 0);
+    assert!(report.counts.iter().all(|count| count.ids.is_empty()));
+}
+
+#[test]
+fn unicode_ids() {
+    let ids = ["żżźa", "ąąą", "abcabc", "zaa"];
+    let report = Checksum::product(vec![2, 3]).compute(&ids);
+    assert_eq!(report.counts[0].ids, vec![0, 2, 3]);
+    assert_eq!(report.counts[1].ids, vec![1]);
+    assert_eq!(report.checksum, 3);
+}
+
+#[test]
+fn many_ids() {
+    let ids: Vec<String> = (0..10_000)
+        .map(|i| format!("{}{}", "ab".repeat(i % 4), "c".repeat(i % 5)))
+        .collect();
+    let report = Checksum::product(vec![2, 3]).compute(&ids);
+    let expected: Vec<usize> = (0..10_000).filter(|i| i % 4 == 2 || i % 5 == 2).collect();
+    assert_eq!(report.counts[0].ids, expected);
+}
+
+#[test]
+fn combine_needs_not_be_sync() {
+    let calls = Cell::new(0);
+    let checksum = Checksum::new(vec![2], |counts| {
+        calls.set(calls.get() + 1);
+        counts[0]
+    });
+    assert_eq!(checksum.compute(&["aab", "abc"]).checksum, 1);
+    assert_eq!(calls.get(), 1);
+}
+
+#[cfg(feature = "rayon")]
+#[test]
+fn parallel_matches_sequential() {
+    let ids: Vec<String> = (0..10_000)
+        .map(|i| format!("{}{}", "ab".repeat(i % 4), "c".repeat(i % 7)))
+        .collect();
+    let checksum = Checksum::product(vec![2, 3]);
+    assert_eq!(checksum.compute_parallel(&ids), checksum.compute(&ids));
+}
@@ -0,0 +1,28 @@
+use aoc_2::Day2;
+use aoc_common::{Answer, Solution};
+
+#[test]
+fn checksum() {
+    let input = Day2
+        .parse("abcdef\nbababc\nabbcde\nabcccd\naabcdd\nabcdee\nababab\n")
+        .unwrap();
+    assert_eq!(Day2.part1(&input).unwrap(), Answer::Number(12));
+}
+
+#[test]
+fn common_letters() {
+    let input = Day2
+        .parse("abcde\nfghij\nklmno\npqrst\nfguij\naxcye\nwvxyz\n")
+        .unwrap();
+    assert_eq!(Day2.part2(&input).unwrap(), Answer::from("fgij"));
+}
+
+#[test]
+fn common_letters_edge_cases() {
+    let part2 = |input: &str| Day2.part2(&Day2.parse(input).unwrap());
+    assert_eq!(part2("abc\nabc\nabd\n").unwrap(), Answer::from("ab"));
+    assert_eq!(part2("xbc\nabc\n").unwrap(), Answer::from("bc"));
+    assert_eq!(part2("zażółć\nzażółw\n").unwrap(), Answer::from("zażół"));
+    assert!(part2("abc\nabc\n").is_err());
+    assert!(part2("abcd\nabd\n").is_err());
+}
@@ -0,0 +1,86 @@
+use aoc_2::{Inventory, Metric, Pair};
+
+#[test]
+fn distances() {
+    assert_eq!(Metric::Hamming.distance("abcde", "axcye"), Some(2));
+    assert_eq!(Metric::Hamming.distance("abc", "abcd"), None);
+    assert_eq!(Metric::Hamming.distance("", ""), Some(0));
+    assert_eq!(Metric::Levenshtein.distance("kitten", "sitting"), Some(3));
+    assert_eq!(Metric::Levenshtein.distance("abc", "abcd"), Some(1));
+    assert_eq!(Metric::Levenshtein.distance("abcd", "bcd"), Some(1));
+    assert_eq!(Metric::Levenshtein.distance("", "xyz"), Some(3));
+    assert_eq!(Metric::Levenshtein.distance("żółw", "żółć"), Some(1));
+}
+
+#[test]
+fn distances_within_limit() {
+    assert_eq!(
+        Metric::Levenshtein.distance_within("kitten", "sitting", 2),
+        None
+    );
+    assert_eq!(
+        Metric::Levenshtein.distance_within("kitten", "sitting", 3),
+        Some(3)
+    );
+    assert_eq!(Metric::Levenshtein.distance_within("a", "abcdef", 4), None);
+    assert_eq!(Metric::Hamming.distance_within("abcde", "axcye", 1), None);
+}
+
+#[test]
+fn pairs_within() {
+    let inventory = Inventory::new(vec!["abcde", "fghij", "klmno", "pqrst", "fguij", "axcye"]);
+    assert_eq!(
+        inventory.pairs_within(Metric::Hamming, 1),
+        vec![Pair {
+            first: 1,
+            second: 4,
+            distance: 1
+        }]
+    );
+    assert_eq!(
+        inventory.pairs_within(Metric::Hamming, 2),
+        vec![
+            Pair {
+                first: 0,
+                second: 5,
+                distance: 2
+            },
+            Pair {
+                first: 1,
+                second: 4,
+                distance: 1
+            },
+        ]
+    );
+
+    let inventory = Inventory::new(vec!["abc", "abcd", "xabc"]);
+    assert!(inventory.pairs_within(Metric::Hamming, 1).is_empty());
+    let distances: Vec<_> = inventory
+        .pairs_within(Metric::Levenshtein, 1)
+        .iter()
+        .map(|pair| (pair.first, pair.second))
+        .collect();
+    assert_eq!(distances, vec![(0, 1), (0, 2)]);
+}
+
+#[test]
+fn nearest() {
+    let inventory = Inventory::new(vec!["abcde", "fghij", "fguij", "abcdef"]);
+    assert_eq!(
+        inventory.nearest(Metric::Hamming, "fgxij"),
+        Some(("fghij", 1))
+    );
+    assert_eq!(inventory.nearest(Metric::Hamming, "abcdefg"), None);
+    assert_eq!(
+        inventory.nearest(Metric::Levenshtein, "abcdefg"),
+        Some(("abcdef", 1))
+    );
+    assert_eq!(
+        inventory.nearest(Metric::Levenshtein, "fguij"),
+        Some(("fguij", 0))
+    );
+    assert_eq!(
+        Inventory::default().nearest(Metric::Levenshtein, "abc"),
+        None
+    );
+}
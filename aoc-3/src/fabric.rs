@@ -0,0 +1,129 @@
+use crate::Claim;
+use std::collections::{BTreeSet, HashMap};
+use std::iter::FromIterator;
+
+/// A piece of fabric claims can be added to and withdrawn from, keeping track of the contested
+/// area and of the claims overlapping no other one as it goes.
+///
+/// Every change only visits the cells of the claim it adds or withdraws.
+#[derive(Clone, Debug, Default)]
+pub struct Fabric {
+    claims: HashMap<usize, Claim>,
+    /// The IDs of the claims covering each claimed cell.
+    cells: HashMap<(usize, usize), Vec<usize>>,
+    /// The IDs of the claims overlapping each claim.
+    conflicts: HashMap<usize, BTreeSet<usize>>,
+    intact: BTreeSet<usize>,
+    overlapping_area: usize,
+}
+
+fn cells(claim: &Claim) -> impl Iterator<Item = (usize, usize)> {
+    let (x, y, w, h) = (claim.x, claim.y, claim.w, claim.h);
+    (x..x + w).flat_map(move |x| (y..y + h).map(move |y| (x, y)))
+}
+
+impl Fabric {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Adds a claim, returning the claim with the same ID it replaces, if any.
+    pub fn add(&mut self, claim: Claim) -> Option<Claim> {
+        let replaced = self.withdraw(claim.id);
+        let mut conflicts = BTreeSet::new();
+        for cell in cells(&claim) {
+            let owners = self.cells.entry(cell).or_default();
+            if owners.len() == 1 {
+                self.overlapping_area += 1;
+            }
+            conflicts.extend(owners.iter().copied());
+            owners.push(claim.id);
+        }
+        for &other in &conflicts {
+            let _ = self.intact.remove(&other);
+            let _ = self
+                .conflicts
+                .get_mut(&other)
+                .expect("every claim has conflicts")
+                .insert(claim.id);
+        }
+        if conflicts.is_empty() {
+            let _ = self.intact.insert(claim.id);
+        }
+        let _ = self.conflicts.insert(claim.id, conflicts);
+        let _ = self.claims.insert(claim.id, claim);
+        replaced
+    }
+
+    /// Withdraws the claim with the given ID, returning it if there was one.
+    pub fn withdraw(&mut self, id: usize) -> Option<Claim> {
+        let claim = self.claims.remove(&id)?;
+        for cell in cells(&claim) {
+            let owners = self
+                .cells
+                .get_mut(&cell)
+                .expect("claimed cells have owners");
+            owners.retain(|&owner| owner != id);
+            match owners.len() {
+                0 => {
+                    let _ = self.cells.remove(&cell);
+                }
+                1 => self.overlapping_area -= 1,
+                _ => {}
+            }
+        }
+        let conflicts = self
+            .conflicts
+            .remove(&id)
+            .expect("every claim has conflicts");
+        for other in conflicts {
+            let others = self
+                .conflicts
+                .get_mut(&other)
+                .expect("every claim has conflicts");
+            let _ = others.remove(&id);
+            if others.is_empty() {
+                let _ = self.intact.insert(other);
+            }
+        }
+        let _ = self.intact.remove(&id);
+        Some(claim)
+    }
+
+    pub fn claim(&self, id: usize) -> Option<&Claim> {
+        self.claims.get(&id)
+    }
+
+    pub fn len(&self) -> usize {
+        self.claims.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.claims.is_empty()
+    }
+
+    /// The area covered by at least two claims.
+    pub fn overlapping_area(&self) -> usize {
+        self.overlapping_area
+    }
+
+    /// The IDs of the claims overlapping no other claim, in increasing order.
+    pub fn intact(&self) -> impl Iterator<Item = usize> + '_ {
+        self.intact.iter().copied()
+    }
+
+    /// The IDs of the claims overlapping the claim with the given ID, if there is one.
+    pub fn conflicts(&self, id: usize) -> Option<&BTreeSet<usize>> {
+        self.conflicts.get(&id)
+    }
+}
+
+impl FromIterator<Claim> for Fabric {
+    fn from_iter<I: IntoIterator<Item = Claim>>(claims: I) -> Self {
+        let mut fabric = Self::new();
+        for claim in claims {
+            let _ = fabric.add(claim);
+        }
+        fabric
+    }
+}
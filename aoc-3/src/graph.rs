@@ -0,0 +1,160 @@
+use crate::{overlapping_pairs, Claim};
+use std::fmt::{self, Write};
+
+/// A rectangle of fabric, in the same units as the claims.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Rect {
+    pub x: usize,
+    pub y: usize,
+    pub w: usize,
+    pub h: usize,
+}
+
+impl Rect {
+    pub fn area(&self) -> usize {
+        self.w * self.h
+    }
+}
+
+/// Written like the position and size of a claim, `x,y: wxh`.
+impl fmt::Display for Rect {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{},{}: {}x{}", self.x, self.y, self.w, self.h)
+    }
+}
+
+/// Another claim overlapping a claim, and the part of the fabric both of them cover.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Overlap {
+    pub id: usize,
+    pub rect: Rect,
+}
+
+/// A claim and all the claims it overlaps, in their original order.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Node {
+    pub claim: Claim,
+    pub overlaps: Vec<Overlap>,
+}
+
+/// Which claims overlap which, and where.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct OverlapGraph {
+    nodes: Vec<Node>,
+    /// The indices of the overlapping nodes, for each node.
+    links: Vec<Vec<usize>>,
+}
+
+impl OverlapGraph {
+    pub fn new(claims: &[Claim]) -> Self {
+        let mut nodes: Vec<Node> = claims
+            .iter()
+            .map(|&claim| Node {
+                claim,
+                overlaps: Vec::new(),
+            })
+            .collect();
+        let mut links = vec![Vec::new(); claims.len()];
+        // With the pairs in order, every claim gets its overlaps in order too.
+        let mut pairs = overlapping_pairs(claims);
+        pairs.sort_unstable_by_key(|&(i, j, _)| (i, j));
+        for (i, j, rect) in pairs {
+            links[i].push(j);
+            links[j].push(i);
+            nodes[i].overlaps.push(Overlap {
+                id: claims[j].id,
+                rect,
+            });
+            nodes[j].overlaps.push(Overlap {
+                id: claims[i].id,
+                rect,
+            });
+        }
+        Self { nodes, links }
+    }
+
+    /// The claims in their original order, with their overlaps.
+    pub fn nodes(&self) -> &[Node] {
+        &self.nodes
+    }
+
+    /// The claim with the given ID, with its overlaps.
+    pub fn node(&self, id: usize) -> Option<&Node> {
+        self.nodes.iter().find(|node| node.claim.id == id)
+    }
+
+    /// The IDs of the claims linked by overlaps, each group in the original order of the claims
+    /// and the groups in the order of their first claims. A claim overlapping nothing is a group
+    /// of its own.
+    pub fn components(&self) -> Vec<Vec<usize>> {
+        let mut component_of = vec![None; self.nodes.len()];
+        let mut components: Vec<Vec<usize>> = Vec::new();
+        for start in 0..self.nodes.len() {
+            if component_of[start].is_some() {
+                continue;
+            }
+            let component = components.len();
+            component_of[start] = Some(component);
+            let mut members = vec![start];
+            let mut queue = vec![start];
+            while let Some(i) = queue.pop() {
+                for &j in &self.links[i] {
+                    if component_of[j].is_none() {
+                        component_of[j] = Some(component);
+                        members.push(j);
+                        queue.push(j);
+                    }
+                }
+            }
+            members.sort_unstable();
+            components.push(
+                members
+                    .into_iter()
+                    .map(|i| self.nodes[i].claim.id)
+                    .collect(),
+            );
+        }
+        components
+    }
+
+    /// One line per claim listing the claims it overlaps and where, like
+    /// `#1 -> #2 @ 3,3: 2x2, #4 @ 5,5: 1x1`.
+    pub fn to_adjacency_list(&self) -> String {
+        let mut out = String::new();
+        for node in &self.nodes {
+            let _ = write!(out, "#{} ->", node.claim.id);
+            for (n, overlap) in node.overlaps.iter().enumerate() {
+                let separator = if n == 0 { "" } else { "," };
+                let _ = write!(out, "{} #{} @ {}", separator, overlap.id, overlap.rect);
+            }
+            out.push('\n');
+        }
+        out
+    }
+
+    /// The graph in the DOT language of Graphviz, with every overlap labelled by its area.
+    pub fn to_dot(&self) -> String {
+        let mut out = String::from("graph overlaps {\n");
+        for node in &self.nodes {
+            let _ = writeln!(out, "    {};", node.claim.id);
+        }
+        for (i, node) in self.nodes.iter().enumerate() {
+            // Every overlap is listed by both claims, but drawn once.
+            let later = self.links[i]
+                .iter()
+                .zip(&node.overlaps)
+                .filter(|&(&j, _)| j > i);
+            for (_, overlap) in later {
+                let _ = writeln!(
+                    out,
+                    "    {} -- {} [label=\"{}\"];",
+                    node.claim.id,
+                    overlap.id,
+                    overlap.rect.area()
+                );
+            }
+        }
+        out.push_str("}\n");
+        out
+    }
+}
@@ -0,0 +1,169 @@
+use crate::{intact_claims, Claim};
+use aoc_common::SolveError;
+use std::io::{self, Write};
+
+const UNCLAIMED: [u8; 3] = [255, 255, 255];
+const CLAIMED_ONCE: [u8; 3] = [198, 219, 239];
+const INTACT: [u8; 3] = [49, 163, 84];
+/// The colours of cells claimed twice and of the most contested cells; the rest are in between.
+const CONTESTED: ([u8; 3], [u8; 3]) = ([253, 174, 97], [165, 0, 38]);
+
+/// How many claims cover each cell of the fabric, over the smallest rectangle holding all the
+/// claims, with the claims overlapping no other one marked.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Heatmap {
+    x: usize,
+    y: usize,
+    width: usize,
+    height: usize,
+    counts: Vec<u32>,
+    intact: Vec<bool>,
+}
+
+impl Heatmap {
+    /// The most cells a heatmap may have, as many as 4096 by 4096, which take a few hundred
+    /// megabytes to draw.
+    pub const MAX_CELLS: usize = 1 << 24;
+
+    /// Fails if the rectangle holding all the claims has more than `MAX_CELLS` cells, like when
+    /// a few claims are far apart.
+    pub fn new(claims: &[Claim]) -> Result<Self, SolveError> {
+        let claims: Vec<&Claim> = claims.iter().filter(|c| c.w > 0 && c.h > 0).collect();
+        let x = claims.iter().map(|c| c.x).min().unwrap_or(0);
+        let y = claims.iter().map(|c| c.y).min().unwrap_or(0);
+        let width = claims.iter().map(|c| c.x + c.w - x).max().unwrap_or(0);
+        let height = claims.iter().map(|c| c.y + c.h - y).max().unwrap_or(0);
+        match width.checked_mul(height) {
+            Some(cells) if cells <= Self::MAX_CELLS => {}
+            _ => {
+                return Err(SolveError::new(format!(
+                    "the claims span {}x{} cells, more than a heatmap can have",
+                    width, height
+                )))
+            }
+        }
+
+        // Each claim adds one at its top left corner and takes it back past its other corners,
+        // so that summing up the changes above and to the left of a cell counts the claims on it.
+        let mut counts = vec![0i64; (width + 1) * (height + 1)];
+        for claim in &claims {
+            let (left, top) = (claim.x - x, claim.y - y);
+            let (right, bottom) = (left + claim.w, top + claim.h);
+            counts[top * (width + 1) + left] += 1;
+            counts[top * (width + 1) + right] -= 1;
+            counts[bottom * (width + 1) + left] -= 1;
+            counts[bottom * (width + 1) + right] += 1;
+        }
+        for row in 0..=height {
+            for col in 1..=width {
+                counts[row * (width + 1) + col] += counts[row * (width + 1) + col - 1];
+            }
+        }
+        for row in 1..=height {
+            for col in 0..=width {
+                counts[row * (width + 1) + col] += counts[(row - 1) * (width + 1) + col];
+            }
+        }
+        let counts = (0..height)
+            .flat_map(|row| (0..width).map(move |col| (row, col)))
+            .map(|(row, col)| counts[row * (width + 1) + col] as u32)
+            .collect();
+
+        let mut intact = vec![false; width * height];
+        let claims: Vec<Claim> = claims.into_iter().copied().collect();
+        for claim in intact_claims(&claims) {
+            for row in claim.y - y..claim.y - y + claim.h {
+                let start = row * width + claim.x - x;
+                intact[start..start + claim.w].fill(true);
+            }
+        }
+
+        Ok(Self {
+            x,
+            y,
+            width,
+            height,
+            counts,
+            intact,
+        })
+    }
+
+    /// The position of the top left cell on the fabric.
+    pub fn origin(&self) -> (usize, usize) {
+        (self.x, self.y)
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    /// How many claims cover the cell at the given position on the fabric.
+    pub fn count(&self, x: usize, y: usize) -> u32 {
+        self.index(x, y).map_or(0, |i| self.counts[i])
+    }
+
+    /// Whether the cell at the given position on the fabric belongs to a claim overlapping no
+    /// other claim.
+    pub fn is_intact(&self, x: usize, y: usize) -> bool {
+        self.index(x, y).is_some_and(|i| self.intact[i])
+    }
+
+    fn index(&self, x: usize, y: usize) -> Option<usize> {
+        let (col, row) = (x.checked_sub(self.x)?, y.checked_sub(self.y)?);
+        if col < self.width && row < self.height {
+            Some(row * self.width + col)
+        } else {
+            None
+        }
+    }
+
+    /// The colours of the cells, row by row, as RGB triples: white where nobody claimed the
+    /// fabric, pale blue where one claim did, green over the intact claims, and from orange to
+    /// dark red as the number of overlapping claims goes up to the largest one.
+    pub fn rgb(&self) -> Vec<u8> {
+        let most = self.counts.iter().copied().max().unwrap_or(0);
+        let mut pixels = Vec::with_capacity(3 * self.counts.len());
+        for (&count, &intact) in self.counts.iter().zip(&self.intact) {
+            let colour = match count {
+                _ if intact => INTACT,
+                0 => UNCLAIMED,
+                1 => CLAIMED_ONCE,
+                _ => {
+                    let (low, high) = CONTESTED;
+                    let (step, steps) = (count - 2, most.saturating_sub(2).max(1));
+                    let mut colour = [0; 3];
+                    for (channel, (&low, &high)) in colour.iter_mut().zip(low.iter().zip(&high)) {
+                        let (low, high) = (i64::from(low), i64::from(high));
+                        let mixed = low + (high - low) * i64::from(step) / i64::from(steps);
+                        *channel = mixed as u8;
+                    }
+                    colour
+                }
+            };
+            pixels.extend_from_slice(&colour);
+        }
+        pixels
+    }
+
+    /// Writes the heatmap as a binary PPM image, one pixel per cell.
+    pub fn write_ppm<W: Write>(&self, mut out: W) -> io::Result<()> {
+        write!(out, "P6\n{} {}\n255\n", self.width, self.height)?;
+        out.write_all(&self.rgb())?;
+        out.flush()
+    }
+
+    /// Writes the heatmap as a PNG image, one pixel per cell.
+    #[cfg(feature = "png")]
+    pub fn write_png<W: Write>(&self, out: W) -> Result<(), png::EncodingError> {
+        let mut encoder = png::Encoder::new(out, self.width as u32, self.height as u32);
+        encoder.set_color(png::ColorType::Rgb);
+        encoder.set_depth(png::BitDepth::Eight);
+        let mut writer = encoder.write_header()?;
+        writer.write_image_data(&self.rgb())?;
+        writer.finish()
+    }
+}
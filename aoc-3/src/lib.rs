@@ -0,0 +1,164 @@
+use aoc_common::parsers::{bare_num, parse_lines, token, ParseResult};
+use aoc_common::{Answer, ParseError, Solution, SolveError};
+use nom::sequence::preceded;
+use nom::Parser;
+
+mod fabric;
+mod graph;
+mod heatmap;
+
+pub use crate::fabric::Fabric;
+pub use crate::graph::{Node, Overlap, OverlapGraph, Rect};
+pub use crate::heatmap::Heatmap;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Claim {
+    pub id: usize,
+    pub x: usize,
+    pub y: usize,
+    pub w: usize,
+    pub h: usize,
+}
+
+fn claim(input: &str) -> ParseResult<'_, Claim> {
+    (
+        preceded(token("#"), bare_num),
+        preceded(token(" @ "), bare_num),
+        preceded(token(","), bare_num),
+        preceded(token(": "), bare_num),
+        preceded(token("x"), bare_num),
+    )
+        .map(|(id, x, y, w, h)| Claim { id, x, y, w, h })
+        .parse(input)
+}
+
+/// The part of the fabric covered by both claims, if any.
+fn intersection(claim1: &Claim, claim2: &Claim) -> Option<Rect> {
+    let x = claim1.x.max(claim2.x);
+    let y = claim1.y.max(claim2.y);
+    let right = (claim1.x + claim1.w).min(claim2.x + claim2.w);
+    let bottom = (claim1.y + claim1.h).min(claim2.y + claim2.h);
+    if x < right && y < bottom {
+        Some(Rect {
+            x,
+            y,
+            w: right - x,
+            h: bottom - y,
+        })
+    } else {
+        None
+    }
+}
+
+/// The total length covered by at least two of the given intervals.
+fn doubly_covered_length(intervals: &[(usize, usize)]) -> usize {
+    let mut edges: Vec<(usize, i32)> = intervals
+        .iter()
+        .flat_map(|&(start, end)| [(start, 1), (end, -1)])
+        .collect();
+    edges.sort_unstable();
+    let mut length = 0;
+    let mut depth = 0;
+    let mut previous = 0;
+    for (position, change) in edges {
+        if depth >= 2 {
+            length += position - previous;
+        }
+        depth += change;
+        previous = position;
+    }
+    length
+}
+
+/// The area covered by at least two claims.
+///
+/// A vertical line sweeps the fabric from left to right, stopping at the left and right edges of
+/// the claims. Between two stops the claims crossing the line, and so the part of it covered
+/// twice, stay the same.
+fn overlapping_area(claims: &[Claim]) -> usize {
+    let mut stops: Vec<usize> = claims
+        .iter()
+        .flat_map(|claim| [claim.x, claim.x + claim.w])
+        .collect();
+    stops.sort_unstable();
+    stops.dedup();
+
+    let mut by_left_edge: Vec<&Claim> = claims.iter().collect();
+    by_left_edge.sort_unstable_by_key(|claim| claim.x);
+    let mut entering = by_left_edge.into_iter().peekable();
+    let mut crossing: Vec<&Claim> = Vec::new();
+    let mut area = 0;
+    for pair in stops.windows(2) {
+        let (x, next_x) = (pair[0], pair[1]);
+        crossing.retain(|claim| claim.x + claim.w > x);
+        while let Some(claim) = entering.next_if(|claim| claim.x <= x) {
+            if claim.w > 0 {
+                crossing.push(claim);
+            }
+        }
+        let intervals: Vec<_> = crossing
+            .iter()
+            .map(|claim| (claim.y, claim.y + claim.h))
+            .collect();
+        area += (next_x - x) * doubly_covered_length(&intervals);
+    }
+    area
+}
+
+/// The indices of every two overlapping claims, the earlier one first, and where they overlap.
+///
+/// Going through the claims by their left edges, only those still reaching past the current left
+/// edge can overlap the current claim.
+fn overlapping_pairs(claims: &[Claim]) -> Vec<(usize, usize, Rect)> {
+    let mut by_left_edge: Vec<usize> = (0..claims.len()).collect();
+    by_left_edge.sort_unstable_by_key(|&i| claims[i].x);
+    let mut pairs = Vec::new();
+    let mut reaching: Vec<usize> = Vec::new();
+    for i in by_left_edge {
+        let claim = &claims[i];
+        reaching.retain(|&j| claims[j].x + claims[j].w > claim.x);
+        for &j in &reaching {
+            if let Some(rect) = intersection(claim, &claims[j]) {
+                pairs.push((i.min(j), i.max(j), rect));
+            }
+        }
+        reaching.push(i);
+    }
+    pairs
+}
+
+/// The claims overlapping no other claim, in their original order.
+fn intact_claims(claims: &[Claim]) -> Vec<&Claim> {
+    let mut overlapped = vec![false; claims.len()];
+    for (i, j, _) in overlapping_pairs(claims) {
+        overlapped[i] = true;
+        overlapped[j] = true;
+    }
+    claims
+        .iter()
+        .zip(overlapped)
+        .filter(|&(_, overlapped)| !overlapped)
+        .map(|(claim, _)| claim)
+        .collect()
+}
+
+pub struct Day3;
+
+impl Solution for Day3 {
+    type Input = Vec<Claim>;
+
+    fn parse(&self, contents: &str) -> Result<Vec<Claim>, ParseError> {
+        parse_lines(contents, claim)
+    }
+
+    fn part1(&self, claims: &Vec<Claim>) -> Result<Answer, SolveError> {
+        Ok(overlapping_area(claims).into())
+    }
+
+    fn part2(&self, claims: &Vec<Claim>) -> Result<Answer, SolveError> {
+        intact_claims(claims)
+            .first()
+            .map(|claim| claim.id.into())
+            .ok_or_else(|| SolveError::new("every claim overlaps another one"))
+    }
+}
@@ -0,0 +1,4 @@
+//! Fixtures shared by the tests of day 3.
+
+/// The claims of the puzzle statement.
+pub const EXAMPLE: &str = "#1 @ 1,3: 4x4\n#2 @ 3,1: 4x4\n#3 @ 5,5: 2x2\n";
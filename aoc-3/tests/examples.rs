@@ -0,0 +1,69 @@
+mod common;
+
+use aoc_3::Day3;
+use aoc_common::{Answer, Rng, Solution};
+use common::EXAMPLE;
+use std::collections::HashMap;
+
+fn solve(input: &str) -> (Answer, Option<Answer>) {
+    let claims = Day3.parse(input).unwrap();
+    (Day3.part1(&claims).unwrap(), Day3.part2(&claims).ok())
+}
+
+#[test]
+fn example() {
+    let claims = Day3.parse(EXAMPLE).unwrap();
+    assert_eq!(claims.len(), 3);
+    assert_eq!(solve(EXAMPLE), (Answer::Number(4), Some(Answer::Number(3))));
+}
+
+#[test]
+fn beyond_a_thousand() {
+    let input = "#1 @ 999,5000: 10x10\n#2 @ 1005,5005: 10x10\n#3 @ 100000,100000: 1x1\n";
+    assert_eq!(solve(input), (Answer::Number(20), Some(Answer::Number(3))));
+}
+
+#[test]
+fn many_claims_on_one_spot() {
+    let input: String = (1..=300)
+        .map(|id| format!("#{} @ 3,3: 2x2\n", id))
+        .collect();
+    assert_eq!(solve(&input), (Answer::Number(4), None));
+}
+
+#[test]
+fn empty_claims() {
+    let input = "#1 @ 0,0: 10x10\n#2 @ 5,5: 0x3\n#3 @ 2,2: 4x0\n";
+    assert_eq!(solve(input), (Answer::Number(0), Some(Answer::Number(1))));
+}
+
+#[test]
+fn matches_canvas() {
+    let mut rng = Rng::new(3);
+    let mut random = |high| rng.range(0, high) as usize;
+    for _ in 0..200 {
+        let claims: Vec<_> = (1..=1 + random(8))
+            .map(|id| (id, random(20), random(20), random(8), random(8)))
+            .collect();
+        let input: String = claims
+            .iter()
+            .map(|(id, x, y, w, h)| format!("#{} @ {},{}: {}x{}\n", id, x, y, w, h))
+            .collect();
+
+        let mut canvas = HashMap::new();
+        for &(_, x, y, w, h) in &claims {
+            for cell in (x..x + w).flat_map(|x| (y..y + h).map(move |y| (x, y))) {
+                *canvas.entry(cell).or_insert(0) += 1;
+            }
+        }
+        let overlapping = canvas.values().filter(|&&n| n > 1).count();
+        let intact = claims
+            .iter()
+            .find(|&&(_, x, y, w, h)| (x..x + w).all(|x| (y..y + h).all(|y| canvas[&(x, y)] == 1)));
+        let expected = (
+            Answer::from(overlapping),
+            intact.map(|&(id, ..)| Answer::from(id)),
+        );
+        assert_eq!(solve(&input), expected, "{}", input);
+    }
+}
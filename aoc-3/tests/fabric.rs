@@ -0,0 +1,90 @@
+use aoc_3::{Claim, Day3, Fabric, OverlapGraph};
+use aoc_common::{Answer, Rng, Solution};
+use std::collections::BTreeSet;
+
+fn claim(id: usize, x: usize, y: usize, w: usize, h: usize) -> Claim {
+    Claim { id, x, y, w, h }
+}
+
+fn intact(fabric: &Fabric) -> Vec<usize> {
+    fabric.intact().collect()
+}
+
+#[test]
+fn add_and_withdraw() {
+    let mut fabric = Fabric::new();
+    assert!(fabric.is_empty());
+    assert_eq!(fabric.add(claim(1, 1, 3, 4, 4)), None);
+    assert_eq!(fabric.add(claim(2, 3, 1, 4, 4)), None);
+    assert_eq!(fabric.add(claim(3, 5, 5, 2, 2)), None);
+    assert_eq!(fabric.len(), 3);
+    assert_eq!(fabric.overlapping_area(), 4);
+    assert_eq!(intact(&fabric), vec![3]);
+    assert_eq!(fabric.conflicts(1), Some(&BTreeSet::from([2])));
+
+    assert_eq!(fabric.add(claim(4, 4, 4, 2, 2)), None);
+    assert_eq!(fabric.overlapping_area(), 7);
+    assert!(intact(&fabric).is_empty());
+    assert_eq!(fabric.conflicts(4), Some(&BTreeSet::from([1, 2, 3])));
+
+    assert_eq!(fabric.withdraw(2), Some(claim(2, 3, 1, 4, 4)));
+    assert_eq!(fabric.withdraw(2), None);
+    assert_eq!(fabric.overlapping_area(), 3);
+    assert!(intact(&fabric).is_empty());
+    assert_eq!(fabric.conflicts(2), None);
+
+    assert!(fabric.withdraw(4).is_some());
+    assert_eq!(fabric.overlapping_area(), 0);
+    assert_eq!(intact(&fabric), vec![1, 3]);
+}
+
+#[test]
+fn replace() {
+    let mut fabric: Fabric = vec![claim(1, 0, 0, 2, 2), claim(2, 1, 1, 2, 2)]
+        .into_iter()
+        .collect();
+    assert_eq!(fabric.overlapping_area(), 1);
+    assert_eq!(fabric.add(claim(2, 5, 5, 2, 2)), Some(claim(2, 1, 1, 2, 2)));
+    assert_eq!(fabric.claim(2), Some(&claim(2, 5, 5, 2, 2)));
+    assert_eq!(fabric.overlapping_area(), 0);
+    assert_eq!(intact(&fabric), vec![1, 2]);
+}
+
+#[test]
+fn matches_solution() {
+    let mut rng = Rng::new(7);
+    let mut random = |high| rng.range(0, high) as usize;
+    let mut fabric = Fabric::new();
+    for _ in 0..500 {
+        let id = 1 + random(10);
+        if random(3) == 0 {
+            let _ = fabric.withdraw(id);
+        } else {
+            let _ = fabric.add(claim(id, random(15), random(15), random(6), random(6)));
+        }
+
+        let mut claims: Vec<Claim> = (1..=10)
+            .filter_map(|id| fabric.claim(id).copied())
+            .collect();
+        if claims.is_empty() {
+            continue;
+        }
+        claims.sort_unstable_by_key(|claim| claim.id);
+        assert_eq!(
+            Day3.part1(&claims).unwrap(),
+            Answer::from(fabric.overlapping_area())
+        );
+        let graph = OverlapGraph::new(&claims);
+        let expected: Vec<usize> = graph
+            .nodes()
+            .iter()
+            .filter(|node| node.overlaps.is_empty())
+            .map(|node| node.claim.id)
+            .collect();
+        assert_eq!(intact(&fabric), expected);
+        for node in graph.nodes() {
+            let ids: BTreeSet<usize> = node.overlaps.iter().map(|overlap| overlap.id).collect();
+            assert_eq!(fabric.conflicts(node.claim.id), Some(&ids));
+        }
+    }
+}
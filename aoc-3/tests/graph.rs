@@ -0,0 +1,72 @@
+use aoc_3::{Day3, Overlap, OverlapGraph, Rect};
+use aoc_common::Solution;
+
+const CLAIMS: &str = "#1 @ 1,3: 4x4\n#2 @ 3,1: 4x4\n#3 @ 5,5: 2x2\n#4 @ 6,6: 2x2\n#5 @ 2,2: 3x3\n";
+
+fn graph() -> OverlapGraph {
+    OverlapGraph::new(&Day3.parse(CLAIMS).unwrap())
+}
+
+#[test]
+fn overlaps() {
+    let graph = graph();
+    let overlaps = |id| graph.node(id).unwrap().overlaps.clone();
+    assert_eq!(
+        overlaps(1),
+        vec![
+            Overlap {
+                id: 2,
+                rect: Rect {
+                    x: 3,
+                    y: 3,
+                    w: 2,
+                    h: 2
+                }
+            },
+            Overlap {
+                id: 5,
+                rect: Rect {
+                    x: 2,
+                    y: 3,
+                    w: 3,
+                    h: 2
+                }
+            },
+        ]
+    );
+    let ids = |id| -> Vec<usize> { overlaps(id).iter().map(|o| o.id).collect() };
+    assert_eq!(ids(2), vec![1, 5]);
+    assert_eq!(ids(3), vec![4]);
+    assert_eq!(ids(4), vec![3]);
+    assert_eq!(ids(5), vec![1, 2]);
+    assert!(graph.node(6).is_none());
+}
+
+#[test]
+fn components() {
+    assert_eq!(graph().components(), vec![vec![1, 2, 5], vec![3, 4]]);
+    let input = "#7 @ 0,0: 1x1\n#8 @ 5,5: 2x2\n#9 @ 0,0: 0x0\n";
+    let graph = OverlapGraph::new(&Day3.parse(input).unwrap());
+    assert_eq!(graph.components(), vec![vec![7], vec![8], vec![9]]);
+}
+
+#[test]
+fn adjacency_list() {
+    assert_eq!(
+        graph().to_adjacency_list(),
+        "#1 -> #2 @ 3,3: 2x2, #5 @ 2,3: 3x2\n\
+         #2 -> #1 @ 3,3: 2x2, #5 @ 3,2: 2x3\n\
+         #3 -> #4 @ 6,6: 1x1\n\
+         #4 -> #3 @ 6,6: 1x1\n\
+         #5 -> #1 @ 2,3: 3x2, #2 @ 3,2: 2x3\n"
+    );
+}
+
+#[test]
+fn dot() {
+    assert_eq!(
+        graph().to_dot(),
+        "graph overlaps {\n    1;\n    2;\n    3;\n    4;\n    5;\n    1 -- 2 [label=\"4\"];\n    \
+         1 -- 5 [label=\"6\"];\n    2 -- 5 [label=\"6\"];\n    3 -- 4 [label=\"1\"];\n}\n"
+    );
+}
@@ -0,0 +1,89 @@
+mod common;
+
+use aoc_3::{Day3, Heatmap};
+use aoc_common::Solution;
+use common::EXAMPLE;
+
+fn heatmap(input: &str) -> Heatmap {
+    Heatmap::new(&Day3.parse(input).unwrap()).unwrap()
+}
+
+fn pixel(heatmap: &Heatmap, x: usize, y: usize) -> [u8; 3] {
+    let (left, top) = heatmap.origin();
+    let i = 3 * ((y - top) * heatmap.width() + x - left);
+    let rgb = heatmap.rgb();
+    [rgb[i], rgb[i + 1], rgb[i + 2]]
+}
+
+#[test]
+fn counts() {
+    let heatmap = heatmap(EXAMPLE);
+    assert_eq!(heatmap.origin(), (1, 1));
+    assert_eq!((heatmap.width(), heatmap.height()), (6, 6));
+    assert_eq!(heatmap.count(3, 3), 2);
+    assert_eq!(heatmap.count(4, 4), 2);
+    assert_eq!(heatmap.count(1, 3), 1);
+    assert_eq!(heatmap.count(1, 1), 0);
+    assert_eq!(heatmap.count(0, 0), 0);
+    assert_eq!(heatmap.count(100, 3), 0);
+    assert!(heatmap.is_intact(6, 6));
+    assert!(!heatmap.is_intact(3, 3));
+    assert!(!heatmap.is_intact(1, 3));
+}
+
+#[test]
+fn colours() {
+    let heatmap = heatmap("#1 @ 0,0: 3x1\n#2 @ 1,0: 2x1\n#3 @ 2,0: 1x1\n#4 @ 0,2: 1x1\n");
+    assert_eq!(pixel(&heatmap, 0, 0), [198, 219, 239]);
+    assert_eq!(pixel(&heatmap, 1, 0), [253, 174, 97]);
+    assert_eq!(pixel(&heatmap, 2, 0), [165, 0, 38]);
+    assert_eq!(pixel(&heatmap, 0, 1), [255, 255, 255]);
+    assert_eq!(pixel(&heatmap, 0, 2), [49, 163, 84]);
+}
+
+#[test]
+fn ppm() {
+    let heatmap = heatmap(EXAMPLE);
+    let mut out = Vec::new();
+    heatmap.write_ppm(&mut out).unwrap();
+    let header = b"P6\n6 6\n255\n";
+    assert_eq!(&out[..header.len()], header);
+    assert_eq!(&out[header.len()..], &heatmap.rgb()[..]);
+    assert_eq!(out.len(), header.len() + 6 * 6 * 3);
+}
+
+#[test]
+fn nothing_claimed() {
+    let heatmap = heatmap("#1 @ 3,3: 0x0\n");
+    assert_eq!((heatmap.width(), heatmap.height()), (0, 0));
+    assert!(heatmap.rgb().is_empty());
+}
+
+#[test]
+fn too_large() {
+    let claims = Day3
+        .parse("#1 @ 999,5000: 10x10\n#2 @ 1005,5005: 10x10\n#3 @ 100000,100000: 1x1\n")
+        .unwrap();
+    let err = Heatmap::new(&claims).unwrap_err();
+    assert_eq!(
+        err.reason,
+        "the claims span 99002x95001 cells, more than a heatmap can have"
+    );
+    let claims = Day3.parse("#1 @ 0,0: 4096x4096\n").unwrap();
+    assert_eq!(4096 * 4096, Heatmap::MAX_CELLS);
+    assert!(Heatmap::new(&claims).is_ok());
+    let claims = Day3.parse("#1 @ 0,0: 4097x4096\n").unwrap();
+    let err = Heatmap::new(&claims).unwrap_err();
+    assert_eq!(
+        err.reason,
+        "the claims span 4097x4096 cells, more than a heatmap can have"
+    );
+}
+
+#[cfg(feature = "png")]
+#[test]
+fn png() {
+    let mut out = Vec::new();
+    heatmap(EXAMPLE).write_png(&mut out).unwrap();
+    assert_eq!(&out[..8], b"\x89PNG\r\n\x1a\n");
+}
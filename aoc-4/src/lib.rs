@@ -0,0 +1,260 @@
+use crate::validate::Step;
+use aoc_common::parsers::{bare_num, expecting, fail, parse_lines, token, ParseResult};
+use aoc_common::{Answer, Expected, ParamError, Params, ParseError, Solution, SolveError};
+use chrono::{NaiveDate, NaiveDateTime, NaiveTime, Timelike};
+use nom::branch::alt;
+use nom::combinator::value;
+use nom::sequence::{delimited, preceded, separated_pair};
+use nom::Parser;
+use std::cmp::{Ord, Ordering, PartialOrd};
+use std::collections::HashMap;
+use std::fmt;
+
+mod timeline;
+mod validate;
+mod window;
+
+pub use crate::timeline::{Night, Timeline};
+pub use crate::validate::{validate, Anomaly, AnomalyKind};
+pub use crate::window::Window;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Event {
+    StartShift(usize),
+    WakeUp,
+    FallAsleep,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct LogEntry {
+    pub timestamp: NaiveDateTime,
+    pub event: Event,
+}
+
+/// Written as it appears in the log.
+impl fmt::Display for LogEntry {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "[{}] ", self.timestamp.format("%Y-%m-%d %H:%M"))?;
+        match self.event {
+            Event::StartShift(id) => write!(f, "Guard #{} begins shift", id),
+            Event::WakeUp => f.write_str("wakes up"),
+            Event::FallAsleep => f.write_str("falls asleep"),
+        }
+    }
+}
+
+/// Entries are ordered by their timestamps only.
+impl PartialOrd for LogEntry {
+    fn partial_cmp(&self, other: &LogEntry) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for LogEntry {
+    fn cmp(&self, other: &LogEntry) -> Ordering {
+        self.timestamp.cmp(&other.timestamp)
+    }
+}
+
+fn date(input: &str) -> ParseResult<'_, NaiveDate> {
+    let (rest, (year, month, day)) = (
+        bare_num,
+        preceded(token("-"), bare_num),
+        preceded(token("-"), bare_num),
+    )
+        .parse(input)?;
+    match NaiveDate::from_ymd_opt(year, month, day) {
+        Some(date) => Ok((rest, date)),
+        None => fail(input, Expected::Item("a valid date")),
+    }
+}
+
+fn time(input: &str) -> ParseResult<'_, NaiveTime> {
+    let (rest, (hour, minute)) = separated_pair(bare_num, token(":"), bare_num).parse(input)?;
+    match NaiveTime::from_hms_opt(hour, minute, 0) {
+        Some(time) => Ok((rest, time)),
+        None => fail(input, Expected::Item("a valid time")),
+    }
+}
+
+fn datetime(input: &str) -> ParseResult<'_, NaiveDateTime> {
+    separated_pair(date, token(" "), time)
+        .map(|(date, time)| date.and_time(time))
+        .parse(input)
+}
+
+fn start_shift(input: &str) -> ParseResult<'_, usize> {
+    delimited(token("Guard #"), bare_num, token(" begins shift")).parse(input)
+}
+
+fn event(input: &str) -> ParseResult<'_, Event> {
+    expecting(
+        Expected::Item("a guard beginning a shift, waking up or falling asleep"),
+        alt((
+            start_shift.map(Event::StartShift),
+            value(Event::WakeUp, token("wakes up")),
+            value(Event::FallAsleep, token("falls asleep")),
+        )),
+    )
+    .parse(input)
+}
+
+fn entry(input: &str) -> ParseResult<'_, LogEntry> {
+    (delimited(token("["), datetime, token("] ")), event)
+        .map(|(timestamp, event)| LogEntry { timestamp, event })
+        .parse(input)
+}
+
+/// How many times a guard slept through each minute of the window.
+type Minutes = Vec<usize>;
+
+struct Analyser {
+    timeline: Timeline,
+    guards: HashMap<usize, Minutes>,
+}
+
+impl Analyser {
+    fn new(window: Window) -> Self {
+        Self {
+            timeline: Timeline::new(window),
+            guards: HashMap::new(),
+        }
+    }
+
+    fn update(&mut self, step: Step) {
+        match step {
+            Step::Shift { guard, night } => self.timeline.start_shift(guard, night),
+            Step::Sleep {
+                guard,
+                night,
+                start,
+                end,
+            } => {
+                self.timeline.record(guard, night, start, end);
+                self.record(guard, start, end);
+            }
+            Step::Anomaly(_) => {}
+        }
+    }
+
+    fn record(&mut self, guard: usize, start: usize, end: usize) {
+        let minutes = self.timeline.window().minutes() as usize;
+        let guard_entry = self.guards.entry(guard).or_insert_with(|| vec![0; minutes]);
+        for minute in start..end {
+            (*guard_entry)[minute] += 1;
+        }
+    }
+
+    fn max_guard(&self) -> usize {
+        self.guards
+            .iter()
+            .max_by_key(|(_, minutes)| minutes.iter().cloned().sum::<usize>())
+            .map(|(&guard, _)| guard)
+            .unwrap()
+    }
+
+    fn max_minute_for_guard(&self, guard: usize) -> usize {
+        self.guards[&guard]
+            .iter()
+            .enumerate()
+            .max_by_key(|(_, &x)| x)
+            .map(|(i, _)| i)
+            .unwrap()
+    }
+
+    fn max_guard_and_minute(&self) -> (usize, usize) {
+        self.guards
+            .iter()
+            .flat_map(|(&guard, minutes)| {
+                minutes
+                    .iter()
+                    .enumerate()
+                    .map(move |(i, &mins)| (guard, i, mins))
+            })
+            .max_by_key(|(_, _, mins)| *mins)
+            .map(|(guard, index, _)| (guard, index))
+            .unwrap()
+    }
+}
+
+/// The puzzle asks for the minute of the hour, but a window longer than an hour can hold the same
+/// minute more than once, so the time of day is given too.
+fn guard_answer(guard: usize, time: NaiveTime) -> Answer {
+    let minute = time.minute() as usize;
+    Answer::Composite(vec![
+        ("guard", guard.into()),
+        ("minute", minute.into()),
+        ("time", time.format("%H:%M").to_string().into()),
+        ("answer", (guard * minute).into()),
+    ])
+}
+
+#[derive(Default)]
+pub struct Day4 {
+    /// Skips the sleeps taking part in anomalies of the log, like a guard falling asleep twice
+    /// before waking up, and stray entries. Otherwise any anomaly is an error.
+    pub lenient: bool,
+    /// The stretch of every night sleeps are counted in; sleeps outside it are anomalies.
+    pub window: Window,
+}
+
+impl Day4 {
+    fn analyse(&self, log: &[LogEntry]) -> Result<Analyser, SolveError> {
+        let mut analyser = Analyser::new(self.window);
+        let mut anomaly = None;
+        validate::walk(log, self.window, |step| match step {
+            Step::Anomaly(found) => {
+                anomaly.get_or_insert(found);
+            }
+            step => analyser.update(step),
+        });
+        match anomaly {
+            Some(anomaly) if !self.lenient => Err(SolveError::new(format!(
+                "{}; set the `lenient` parameter to skip such entries",
+                anomaly
+            ))),
+            _ => Ok(analyser),
+        }
+    }
+
+    fn analyse_sleeps(&self, log: &[LogEntry]) -> Result<Analyser, SolveError> {
+        let analyser = self.analyse(log)?;
+        if analyser.guards.is_empty() {
+            return Err(SolveError::new("no guard ever falls asleep"));
+        }
+        Ok(analyser)
+    }
+
+    /// Every shift of a sorted log and when the guard slept, failing like the parts do on
+    /// anomalies.
+    pub fn timeline(&self, log: &[LogEntry]) -> Result<Timeline, SolveError> {
+        Ok(self.analyse(log)?.timeline)
+    }
+}
+
+impl Solution for Day4 {
+    type Input = Vec<LogEntry>;
+
+    fn configure(&mut self, params: &mut Params) -> Result<(), ParamError> {
+        params.update("lenient", &mut self.lenient)?;
+        params.update("window", &mut self.window)
+    }
+
+    fn parse(&self, contents: &str) -> Result<Vec<LogEntry>, ParseError> {
+        let mut log = parse_lines(contents, entry)?;
+        log.sort();
+        Ok(log)
+    }
+
+    fn part1(&self, log: &Vec<LogEntry>) -> Result<Answer, SolveError> {
+        let analyser = self.analyse_sleeps(log)?;
+        let max_guard = analyser.max_guard();
+        let max_minute = analyser.max_minute_for_guard(max_guard);
+        Ok(guard_answer(max_guard, self.window.time_at(max_minute)))
+    }
+
+    fn part2(&self, log: &Vec<LogEntry>) -> Result<Answer, SolveError> {
+        let (max_guard, max_minute) = self.analyse_sleeps(log)?.max_guard_and_minute();
+        Ok(guard_answer(max_guard, self.window.time_at(max_minute)))
+    }
+}
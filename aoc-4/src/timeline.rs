@@ -0,0 +1,123 @@
+use crate::Window;
+use chrono::{NaiveDate, Timelike};
+use std::fmt::Write;
+
+/// The shift of one guard on one night, and the minutes of the window the guard slept through.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Night {
+    pub date: NaiveDate,
+    pub guard: usize,
+    pub asleep: Vec<bool>,
+}
+
+impl Night {
+    pub fn minutes_asleep(&self) -> usize {
+        self.asleep.iter().filter(|&&asleep| asleep).count()
+    }
+}
+
+/// Every shift of a log in order, like the table in the puzzle statement.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Timeline {
+    window: Window,
+    nights: Vec<Night>,
+}
+
+impl Timeline {
+    pub(crate) fn new(window: Window) -> Self {
+        Self {
+            window,
+            nights: Vec::new(),
+        }
+    }
+
+    pub(crate) fn start_shift(&mut self, guard: usize, date: NaiveDate) {
+        self.nights.push(Night {
+            date,
+            guard,
+            asleep: vec![false; self.window.minutes() as usize],
+        });
+    }
+
+    /// Marks the minutes from `start` to `end` of the window as slept through, on the current
+    /// shift if it's the guard's on the same night, or on a new one otherwise.
+    pub(crate) fn record(&mut self, guard: usize, date: NaiveDate, start: usize, end: usize) {
+        let current = self.nights.last();
+        if current.is_none_or(|night| (night.date, night.guard) != (date, guard)) {
+            self.start_shift(guard, date);
+        }
+        let night = self
+            .nights
+            .last_mut()
+            .expect("a shift has just been started");
+        night.asleep[start..end].fill(true);
+    }
+
+    pub fn window(&self) -> Window {
+        self.window
+    }
+
+    pub fn nights(&self) -> &[Night] {
+        &self.nights
+    }
+
+    /// The timeline as a table with a row per night, marking the minutes the guard was asleep
+    /// with `#` and the others with `.`, under the tens and ones of every minute.
+    pub fn to_table(&self) -> String {
+        let ids: Vec<String> = self
+            .nights
+            .iter()
+            .map(|n| format!("#{}", n.guard))
+            .collect();
+        let id_width = ids.iter().map(String::len).max().unwrap_or(0).max(2) + 2;
+        let minute_digit = |digit: fn(u32) -> u32| -> String {
+            (0..self.window.minutes() as usize)
+                .map(|offset| {
+                    let minute = self.window.time_at(offset).minute();
+                    char::from_digit(digit(minute), 10).expect("a digit")
+                })
+                .collect()
+        };
+
+        let mut out = String::new();
+        let _ = writeln!(out, "{:<7}{:<w$}Minute", "Date", "ID", w = id_width);
+        let margin = " ".repeat(7 + id_width);
+        let _ = writeln!(out, "{}{}", margin, minute_digit(|minute| minute / 10));
+        let _ = writeln!(out, "{}{}", margin, minute_digit(|minute| minute % 10));
+        for (night, id) in self.nights.iter().zip(&ids) {
+            let marks: String = night
+                .asleep
+                .iter()
+                .map(|&asleep| if asleep { '#' } else { '.' })
+                .collect();
+            let date = night.date.format("%m-%d").to_string();
+            let _ = writeln!(out, "{:<7}{:<w$}{}", date, id, marks, w = id_width);
+        }
+        out
+    }
+
+    /// The timeline as CSV, with a row per night giving its date, the guard, the number of
+    /// minutes the guard slept and a column per minute of the window holding 1 if the guard was
+    /// asleep then and 0 otherwise.
+    pub fn to_csv(&self) -> String {
+        let mut out = String::from("date,guard,minutes_asleep");
+        for offset in 0..self.window.minutes() as usize {
+            let _ = write!(out, ",{}", self.window.time_at(offset).format("%H:%M"));
+        }
+        out.push('\n');
+        for night in &self.nights {
+            let _ = write!(
+                out,
+                "{},{},{}",
+                night.date.format("%Y-%m-%d"),
+                night.guard,
+                night.minutes_asleep()
+            );
+            for &asleep in &night.asleep {
+                out.push_str(if asleep { ",1" } else { ",0" });
+            }
+            out.push('\n');
+        }
+        out
+    }
+}
@@ -0,0 +1,148 @@
+use crate::{Event, LogEntry, Window};
+use chrono::NaiveDate;
+use std::fmt;
+
+/// What's wrong with some entries of a guard log.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum AnomalyKind {
+    /// A guard wakes up without having fallen asleep.
+    WakeWithoutSleep,
+    /// A guard falls asleep while already asleep.
+    DoubleSleep,
+    /// A guard falls asleep before any guard began a shift.
+    SleepWithoutGuard,
+    /// A shift begins while the previous guard is asleep.
+    ShiftWhileAsleep,
+    /// A guard falls asleep or wakes up outside the window of the night, like sleeping through
+    /// midnight when only the midnight hour is watched.
+    OutsideWindow,
+    /// The log ends while a guard is asleep.
+    UnfinishedSleep,
+    /// Two entries have the same timestamp, so their order is unknown.
+    DuplicateTimestamp,
+}
+
+impl fmt::Display for AnomalyKind {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let description = match self {
+            AnomalyKind::WakeWithoutSleep => "a guard wakes up without having fallen asleep",
+            AnomalyKind::DoubleSleep => "a guard falls asleep twice",
+            AnomalyKind::SleepWithoutGuard => "a guard falls asleep before any shift began",
+            AnomalyKind::ShiftWhileAsleep => "a shift begins while a guard is asleep",
+            AnomalyKind::OutsideWindow => "a guard sleeps outside the watched window",
+            AnomalyKind::UnfinishedSleep => "the log ends while a guard is asleep",
+            AnomalyKind::DuplicateTimestamp => "two entries have the same timestamp",
+        };
+        f.write_str(description)
+    }
+}
+
+/// An anomaly and the entries showing it, in the order of the log.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Anomaly {
+    pub kind: AnomalyKind,
+    pub entries: Vec<LogEntry>,
+}
+
+impl fmt::Display for Anomaly {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}:", self.kind)?;
+        for (i, entry) in self.entries.iter().enumerate() {
+            let separator = if i == 0 { "" } else { "," };
+            write!(f, "{} `{}`", separator, entry)?;
+        }
+        Ok(())
+    }
+}
+
+/// What the walk over a log found at an entry.
+pub(crate) enum Step {
+    /// A guard began the shift of a night.
+    Shift {
+        guard: usize,
+        night: NaiveDate,
+    },
+    /// A guard slept well-formedly over the given minutes into the window of a night.
+    Sleep {
+        guard: usize,
+        night: NaiveDate,
+        start: usize,
+        end: usize,
+    },
+    Anomaly(Anomaly),
+}
+
+/// Goes through a sorted log, calling `on_step` with every shift, every well-formed sleep and
+/// every anomaly, in the order of the entries completing them.
+///
+/// A sleep taking part in an anomaly is never reported as well-formed: a guard falling asleep
+/// twice stays asleep since the first time, but that sleep is dropped when the guard wakes up,
+/// and so is a sleep starting or ending at a duplicate timestamp.
+pub(crate) fn walk<F: FnMut(Step)>(log: &[LogEntry], window: Window, mut on_step: F) {
+    let anomaly = |kind, entries: &[&LogEntry]| {
+        Step::Anomaly(Anomaly {
+            kind,
+            entries: entries.iter().map(|&&entry| entry).collect(),
+        })
+    };
+    let mut guard = None;
+    // The entry the guard fell asleep at, and whether the sleep takes part in an anomaly.
+    let mut asleep: Option<(&LogEntry, bool)> = None;
+    let mut previous: Option<&LogEntry> = None;
+    for entry in log {
+        let duplicate = previous.filter(|p| p.timestamp == entry.timestamp);
+        if let Some(previous) = duplicate {
+            on_step(anomaly(AnomalyKind::DuplicateTimestamp, &[previous, entry]));
+        }
+        previous = Some(entry);
+        match entry.event {
+            Event::StartShift(id) => {
+                if let Some((sleep, _)) = asleep.take() {
+                    on_step(anomaly(AnomalyKind::ShiftWhileAsleep, &[sleep, entry]));
+                }
+                guard = Some(id);
+                let night = window.night(window.opening(entry.timestamp));
+                on_step(Step::Shift { guard: id, night });
+            }
+            Event::FallAsleep => match (guard, asleep.as_mut()) {
+                (None, _) => on_step(anomaly(AnomalyKind::SleepWithoutGuard, &[entry])),
+                (Some(_), Some((sleep, tainted))) => {
+                    *tainted = true;
+                    on_step(anomaly(AnomalyKind::DoubleSleep, &[sleep, entry]))
+                }
+                (Some(_), None) => asleep = Some((entry, duplicate.is_some())),
+            },
+            Event::WakeUp => match (guard, asleep.take()) {
+                (Some(_), Some((_, tainted))) if tainted || duplicate.is_some() => {}
+                (Some(guard), Some((sleep, _))) => {
+                    let opening = window.opening(sleep.timestamp);
+                    match window.offsets(opening, sleep.timestamp, entry.timestamp) {
+                        Some((start, end)) => on_step(Step::Sleep {
+                            guard,
+                            night: window.night(opening),
+                            start,
+                            end,
+                        }),
+                        None => on_step(anomaly(AnomalyKind::OutsideWindow, &[sleep, entry])),
+                    }
+                }
+                _ => on_step(anomaly(AnomalyKind::WakeWithoutSleep, &[entry])),
+            },
+        }
+    }
+    if let Some((sleep, _)) = asleep {
+        on_step(anomaly(AnomalyKind::UnfinishedSleep, &[sleep]));
+    }
+}
+
+/// All the anomalies of a sorted log watched over in `window`, in the order of the entries
+/// completing them.
+pub fn validate(log: &[LogEntry], window: Window) -> Vec<Anomaly> {
+    let mut anomalies = Vec::new();
+    walk(log, window, |step| {
+        if let Step::Anomaly(anomaly) = step {
+            anomalies.push(anomaly);
+        }
+    });
+    anomalies
+}
@@ -0,0 +1,107 @@
+use crate::time;
+use aoc_common::parsers::{parse_all, token};
+use aoc_common::ParseError;
+use chrono::{Duration, NaiveDate, NaiveDateTime, NaiveTime, Timelike};
+use nom::sequence::separated_pair;
+use std::fmt;
+use std::str::FromStr;
+
+const MINUTES_PER_DAY: u32 = 24 * 60;
+
+/// The stretch of every night the guards are watched over, like `00:00-01:00`, the midnight
+/// hour. It may span midnight, like `23:00-01:00`.
+///
+/// A night is named after the day its window ends on, so a guard beginning a shift just before
+/// midnight watches the night of the next day.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Window {
+    start: NaiveTime,
+    minutes: u32,
+}
+
+impl Default for Window {
+    fn default() -> Self {
+        Self {
+            start: NaiveTime::MIN,
+            minutes: 60,
+        }
+    }
+}
+
+impl Window {
+    /// A window of the given number of minutes starting at `start` every day, if that's more than
+    /// nothing and no more than a day.
+    pub fn new(start: NaiveTime, minutes: u32) -> Option<Self> {
+        if (1..=MINUTES_PER_DAY).contains(&minutes) {
+            Some(Self { start, minutes })
+        } else {
+            None
+        }
+    }
+
+    pub fn start(&self) -> NaiveTime {
+        self.start
+    }
+
+    pub fn minutes(&self) -> u32 {
+        self.minutes
+    }
+
+    fn length(&self) -> Duration {
+        Duration::minutes(i64::from(self.minutes))
+    }
+
+    /// When the window of the first night ending after `at` opens.
+    pub fn opening(&self, at: NaiveDateTime) -> NaiveDateTime {
+        let mut opening = at.date().and_time(self.start) - Duration::days(1);
+        while opening + self.length() <= at {
+            opening += Duration::days(1);
+        }
+        opening
+    }
+
+    /// The night of the window opening at `opening`.
+    pub fn night(&self, opening: NaiveDateTime) -> NaiveDate {
+        (opening + self.length() - Duration::minutes(1)).date()
+    }
+
+    /// The minutes from `opening` to `from` and to `to`, if both are in the window opening then.
+    pub fn offsets(
+        &self,
+        opening: NaiveDateTime,
+        from: NaiveDateTime,
+        to: NaiveDateTime,
+    ) -> Option<(usize, usize)> {
+        if from < opening || to > opening + self.length() {
+            return None;
+        }
+        let offset = |at: NaiveDateTime| (at - opening).num_minutes() as usize;
+        Some((offset(from), offset(to)))
+    }
+
+    /// The time of day `offset` minutes into the window.
+    pub fn time_at(&self, offset: usize) -> NaiveTime {
+        self.start + Duration::minutes(offset as i64)
+    }
+}
+
+impl fmt::Display for Window {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let end = self.time_at(self.minutes as usize);
+        write!(f, "{}-{}", self.start.format("%H:%M"), end.format("%H:%M"))
+    }
+}
+
+/// Parses a window from its start and end times, like `23:00-01:00`. A window ending when it
+/// starts lasts the whole day.
+impl FromStr for Window {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, ParseError> {
+        let (start, end) = parse_all(s, separated_pair(time, token("-"), time))?;
+        let minute_of_day = |time: NaiveTime| time.hour() * 60 + time.minute();
+        let minutes =
+            (minute_of_day(end) + MINUTES_PER_DAY - minute_of_day(start) - 1) % MINUTES_PER_DAY + 1;
+        Ok(Self { start, minutes })
+    }
+}
@@ -0,0 +1,29 @@
+mod common;
+
+use aoc_4::Day4;
+use aoc_common::{Answer, Solution};
+use common::EXAMPLE;
+
+fn guard_answer(guard: i64, minute: i64) -> Answer {
+    Answer::Composite(vec![
+        ("guard", Answer::Number(guard)),
+        ("minute", Answer::Number(minute)),
+        ("time", Answer::Text(format!("00:{:02}", minute))),
+        ("answer", Answer::Number(guard * minute)),
+    ])
+}
+
+#[test]
+fn example() {
+    let log = Day4::default().parse(EXAMPLE).unwrap();
+    assert_eq!(Day4::default().part1(&log).unwrap(), guard_answer(10, 24));
+    assert_eq!(Day4::default().part2(&log).unwrap(), guard_answer(99, 45));
+}
+
+#[test]
+fn unsorted_log() {
+    let mut lines: Vec<_> = EXAMPLE.lines().collect();
+    lines.reverse();
+    let log = Day4::default().parse(&lines.join("\n")).unwrap();
+    assert_eq!(log, Day4::default().parse(EXAMPLE).unwrap());
+}
@@ -0,0 +1,85 @@
+mod common;
+
+use aoc_4::{Day4, Window};
+use aoc_common::Solution;
+use common::EXAMPLE;
+
+#[test]
+fn example_table() {
+    let day = Day4::default();
+    let timeline = day.timeline(&day.parse(EXAMPLE).unwrap()).unwrap();
+    assert_eq!(
+        timeline.to_table(),
+        "\
+Date   ID   Minute
+            000000000011111111112222222222333333333344444444445555555555
+            012345678901234567890123456789012345678901234567890123456789
+11-01  #10  .....####################.....#########################.....
+11-02  #99  ........................................##########..........
+11-03  #10  ........................#####...............................
+11-04  #99  ....................................##########..............
+11-05  #99  .............................................##########.....
+"
+    );
+    let totals: Vec<_> = timeline
+        .nights()
+        .iter()
+        .map(|night| (night.guard, night.minutes_asleep()))
+        .collect();
+    assert_eq!(
+        totals,
+        vec![(10, 45), (99, 10), (10, 5), (99, 10), (99, 10)]
+    );
+}
+
+#[test]
+fn csv_over_a_window() {
+    let input = "\
+[1518-11-01 23:55] Guard #7 begins shift
+[1518-11-01 23:58] falls asleep
+[1518-11-02 00:01] wakes up
+[1518-11-02 23:59] Guard #1234 begins shift
+";
+    let day = Day4 {
+        window: "23:57-00:03".parse::<Window>().unwrap(),
+        ..Day4::default()
+    };
+    let timeline = day.timeline(&day.parse(input).unwrap()).unwrap();
+    assert_eq!(
+        timeline.to_csv(),
+        "date,guard,minutes_asleep,23:57,23:58,23:59,00:00,00:01,00:02\n\
+         1518-11-02,7,3,0,1,1,1,0,0\n\
+         1518-11-03,1234,0,0,0,0,0,0,0\n"
+    );
+    assert_eq!(
+        timeline.to_table(),
+        "\
+Date   ID     Minute
+              555000
+              789012
+11-02  #7     .###..
+11-03  #1234  ......
+"
+    );
+}
+
+#[test]
+fn lenient_timeline_skips_anomalies() {
+    let input = "\
+[1518-11-01 00:00] Guard #10 begins shift
+[1518-11-01 00:05] falls asleep
+[1518-11-01 00:08] wakes up
+[1518-11-01 00:09] wakes up
+[1518-11-01 00:20] falls asleep
+[1518-11-01 01:20] wakes up
+";
+    let log = Day4::default().parse(input).unwrap();
+    assert!(Day4::default().timeline(&log).is_err());
+    let day = Day4 {
+        lenient: true,
+        ..Day4::default()
+    };
+    let timeline = day.timeline(&log).unwrap();
+    assert_eq!(timeline.nights().len(), 1);
+    assert_eq!(timeline.nights()[0].minutes_asleep(), 3);
+}
@@ -0,0 +1,197 @@
+use aoc_4::{validate, AnomalyKind, Day4, LogEntry, Window};
+use aoc_common::{Answer, Params, Solution};
+
+fn log(lines: &str) -> Vec<LogEntry> {
+    Day4::default().parse(lines).unwrap()
+}
+
+fn anomalies(lines: &str) -> Vec<(AnomalyKind, Vec<String>)> {
+    validate(&log(lines), Window::default())
+        .into_iter()
+        .map(|anomaly| {
+            let entries = anomaly.entries.iter().map(ToString::to_string).collect();
+            (anomaly.kind, entries)
+        })
+        .collect()
+}
+
+#[test]
+fn clean_log() {
+    let lines = "\
+[1518-11-01 23:58] Guard #99 begins shift
+[1518-11-02 00:40] falls asleep
+[1518-11-02 00:50] wakes up
+";
+    assert!(anomalies(lines).is_empty());
+}
+
+#[test]
+fn each_anomaly() {
+    let lines = "\
+[1518-11-01 00:01] falls asleep
+[1518-11-01 00:02] Guard #10 begins shift
+[1518-11-01 00:03] wakes up
+[1518-11-01 00:05] falls asleep
+[1518-11-01 00:07] falls asleep
+[1518-11-01 00:09] wakes up
+[1518-11-01 00:30] falls asleep
+[1518-11-01 23:58] Guard #99 begins shift
+[1518-11-02 23:50] falls asleep
+[1518-11-03 00:10] wakes up
+[1518-11-03 00:50] falls asleep
+[1518-11-03 01:05] wakes up
+[1518-11-04 00:00] Guard #10 begins shift
+[1518-11-04 00:00] Guard #99 begins shift
+[1518-11-04 00:20] falls asleep
+";
+    assert_eq!(
+        anomalies(lines),
+        vec![
+            (
+                AnomalyKind::SleepWithoutGuard,
+                vec!["[1518-11-01 00:01] falls asleep".to_owned()]
+            ),
+            (
+                AnomalyKind::WakeWithoutSleep,
+                vec!["[1518-11-01 00:03] wakes up".to_owned()]
+            ),
+            (
+                AnomalyKind::DoubleSleep,
+                vec![
+                    "[1518-11-01 00:05] falls asleep".to_owned(),
+                    "[1518-11-01 00:07] falls asleep".to_owned()
+                ]
+            ),
+            (
+                AnomalyKind::ShiftWhileAsleep,
+                vec![
+                    "[1518-11-01 00:30] falls asleep".to_owned(),
+                    "[1518-11-01 23:58] Guard #99 begins shift".to_owned()
+                ]
+            ),
+            (
+                AnomalyKind::OutsideWindow,
+                vec![
+                    "[1518-11-02 23:50] falls asleep".to_owned(),
+                    "[1518-11-03 00:10] wakes up".to_owned()
+                ]
+            ),
+            (
+                AnomalyKind::OutsideWindow,
+                vec![
+                    "[1518-11-03 00:50] falls asleep".to_owned(),
+                    "[1518-11-03 01:05] wakes up".to_owned()
+                ]
+            ),
+            (
+                AnomalyKind::DuplicateTimestamp,
+                vec![
+                    "[1518-11-04 00:00] Guard #10 begins shift".to_owned(),
+                    "[1518-11-04 00:00] Guard #99 begins shift".to_owned()
+                ]
+            ),
+            (
+                AnomalyKind::UnfinishedSleep,
+                vec!["[1518-11-04 00:20] falls asleep".to_owned()]
+            ),
+        ]
+    );
+}
+
+#[test]
+fn strict_and_lenient() {
+    let lines = "\
+[1518-11-01 00:00] Guard #10 begins shift
+[1518-11-01 00:05] falls asleep
+[1518-11-01 00:25] wakes up
+[1518-11-01 00:30] wakes up
+[1518-11-02 00:00] Guard #99 begins shift
+[1518-11-02 00:40] falls asleep
+[1518-11-03 00:00] Guard #99 begins shift
+[1518-11-03 00:10] falls asleep
+[1518-11-03 00:15] wakes up
+[1518-11-04 00:00] Guard #10 begins shift
+[1518-11-04 00:10] falls asleep
+[1518-11-04 00:12] wakes up
+";
+    let log = log(lines);
+    let err = Day4::default().part1(&log).unwrap_err();
+    assert_eq!(
+        err.reason,
+        "a guard wakes up without having fallen asleep: `[1518-11-01 00:30] wakes up`; set the \
+         `lenient` parameter to skip such entries"
+    );
+
+    let mut day = Day4::default();
+    let mut params = Params::new();
+    params.set("lenient", "true");
+    day.configure(&mut params).unwrap();
+    params.finish().unwrap();
+    assert_eq!(
+        day.part1(&log).unwrap(),
+        Answer::Composite(vec![
+            ("guard", Answer::Number(10)),
+            ("minute", Answer::Number(11)),
+            ("time", Answer::from("00:11")),
+            ("answer", Answer::Number(110)),
+        ])
+    );
+}
+
+#[test]
+fn nobody_sleeps() {
+    let day = Day4 {
+        lenient: true,
+        ..Day4::default()
+    };
+    let log = log("[1518-11-01 00:00] Guard #10 begins shift\n[1518-11-01 00:30] wakes up\n");
+    assert_eq!(
+        day.part2(&log).unwrap_err().reason,
+        "no guard ever falls asleep"
+    );
+}
+
+#[test]
+fn lenient_skips_tainted_sleeps() {
+    let lines = "\
+[1518-11-01 00:00] Guard #10 begins shift
+[1518-11-01 00:05] falls asleep
+[1518-11-01 00:40] falls asleep
+[1518-11-01 00:50] wakes up
+[1518-11-02 00:00] Guard #99 begins shift
+[1518-11-02 00:10] falls asleep
+[1518-11-02 00:20] wakes up
+[1518-11-03 00:00] Guard #10 begins shift
+[1518-11-03 00:30] falls asleep
+[1518-11-03 00:30] wakes up
+[1518-11-03 00:45] falls asleep
+[1518-11-03 00:47] wakes up
+[1518-11-04 00:00] Guard #99 begins shift
+[1518-11-04 00:05] falls asleep
+[1518-11-04 00:15] wakes up
+[1518-11-04 00:15] falls asleep
+[1518-11-04 00:20] wakes up
+";
+    let log = log(lines);
+    let day = Day4 {
+        lenient: true,
+        ..Day4::default()
+    };
+    let asleep: Vec<_> = day
+        .timeline(&log)
+        .unwrap()
+        .nights()
+        .iter()
+        .map(|night| (night.guard, night.minutes_asleep()))
+        .collect();
+    assert_eq!(asleep, vec![(10, 0), (99, 10), (10, 2), (99, 10)]);
+    assert_eq!(
+        day.part1(&log).unwrap(),
+        Answer::Composite(vec![
+            ("guard", Answer::Number(99)),
+            ("minute", Answer::Number(14)),
+            ("time", Answer::from("00:14")),
+            ("answer", Answer::Number(1386)),
+        ])
+    );
+}
@@ -0,0 +1,108 @@
+use aoc_4::{Day4, Window};
+use aoc_common::{Answer, Expected, Params, Solution};
+use chrono::{NaiveDate, NaiveDateTime, NaiveTime};
+
+fn at(day: u32, hour: u32, minute: u32) -> NaiveDateTime {
+    NaiveDate::from_ymd_opt(1518, 11, day)
+        .unwrap()
+        .and_hms_opt(hour, minute, 0)
+        .unwrap()
+}
+
+#[test]
+fn invalid_timestamps() {
+    let err = Day4::default()
+        .parse("[1518-13-01 00:00] wakes up\n")
+        .unwrap_err();
+    assert_eq!((err.line, err.column), (1, 2));
+    assert_eq!(err.expected, Expected::Item("a valid date"));
+
+    let err = Day4::default()
+        .parse("[1518-02-29 00:00] wakes up\n")
+        .unwrap_err();
+    assert_eq!(err.expected, Expected::Item("a valid date"));
+
+    let err = Day4::default()
+        .parse("[1518-11-01 00:00] Guard #1 begins shift\n[1518-11-01 00:75] wakes up\n")
+        .unwrap_err();
+    assert_eq!((err.line, err.column), (2, 13));
+    assert_eq!(err.expected, Expected::Item("a valid time"));
+}
+
+#[test]
+fn windows() {
+    let hour: Window = "00:00-01:00".parse().unwrap();
+    assert_eq!(hour, Window::default());
+    assert_eq!(hour.minutes(), 60);
+    let late: Window = "23:00-01:00".parse().unwrap();
+    assert_eq!(late.minutes(), 120);
+    assert_eq!(late.to_string(), "23:00-01:00");
+    let day: Window = "06:00-06:00".parse().unwrap();
+    assert_eq!(day.minutes(), 24 * 60);
+    assert_eq!(
+        Window::new(NaiveTime::from_hms_opt(22, 30, 0).unwrap(), 90)
+            .unwrap()
+            .to_string(),
+        "22:30-00:00"
+    );
+    assert_eq!(Window::new(NaiveTime::MIN, 0), None);
+    assert!("25:00-01:00".parse::<Window>().is_err());
+    assert!("00:00".parse::<Window>().is_err());
+}
+
+#[test]
+fn nights() {
+    let hour = Window::default();
+    assert_eq!(hour.opening(at(1, 23, 58)), at(2, 0, 0));
+    assert_eq!(hour.opening(at(2, 0, 59)), at(2, 0, 0));
+    assert_eq!(hour.opening(at(2, 1, 0)), at(3, 0, 0));
+    assert_eq!(hour.night(at(2, 0, 0)), at(2, 0, 0).date());
+
+    let late: Window = "23:00-01:00".parse().unwrap();
+    assert_eq!(late.opening(at(1, 23, 58)), at(1, 23, 0));
+    assert_eq!(late.opening(at(2, 0, 30)), at(1, 23, 0));
+    assert_eq!(late.opening(at(1, 22, 0)), at(1, 23, 0));
+    assert_eq!(late.night(at(1, 23, 0)), at(2, 0, 0).date());
+    assert_eq!(
+        late.offsets(at(1, 23, 0), at(1, 23, 50), at(2, 0, 10)),
+        Some((50, 70))
+    );
+    assert_eq!(
+        late.offsets(at(1, 23, 0), at(1, 23, 50), at(2, 1, 10)),
+        None
+    );
+}
+
+#[test]
+fn sleeps_across_midnight() {
+    let input = "\
+[1518-11-01 23:30] Guard #10 begins shift
+[1518-11-01 23:50] falls asleep
+[1518-11-02 00:10] wakes up
+[1518-11-02 23:55] Guard #7 begins shift
+[1518-11-03 00:05] falls asleep
+[1518-11-03 00:07] wakes up
+[1518-11-03 23:40] Guard #10 begins shift
+[1518-11-03 23:58] falls asleep
+[1518-11-04 00:02] wakes up
+[1518-11-04 23:45] Guard #10 begins shift
+[1518-11-04 23:59] falls asleep
+[1518-11-05 00:00] wakes up
+";
+    let log = Day4::default().parse(input).unwrap();
+    assert!(Day4::default().part1(&log).is_err());
+
+    let mut day = Day4::default();
+    let mut params = Params::new();
+    params.set("window", "23:00-01:00");
+    day.configure(&mut params).unwrap();
+    params.finish().unwrap();
+    let expected = Answer::Composite(vec![
+        ("guard", Answer::Number(10)),
+        ("minute", Answer::Number(59)),
+        ("time", Answer::from("23:59")),
+        ("answer", Answer::Number(590)),
+    ]);
+    assert_eq!(day.part1(&log).unwrap(), expected);
+    assert_eq!(day.part2(&log).unwrap(), expected);
+}
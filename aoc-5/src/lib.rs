@@ -1,12 +1,11 @@
-use std::fs::File;
-use std::io::Read;
+use aoc_common::{Answer, ParseError, Solution, SolveError};
 
 fn check_opposite(c1: char, c2: char) -> bool {
     c1.to_lowercase().next().unwrap() == c2.to_lowercase().next().unwrap() && c1 != c2
 }
 
 fn append(new_str: &mut Vec<char>, c: char) {
-    if new_str.len() > 0 {
+    if !new_str.is_empty() {
         let last_index = new_str.len() - 1;
         let last_c = new_str[last_index];
         if check_opposite(last_c, c) {
@@ -27,25 +26,33 @@ fn collapse<I: IntoIterator<Item = char>>(data: I) -> Vec<char> {
     result
 }
 
-fn main() {
-    let mut file = File::open("input").unwrap();
-    let mut contents = String::new();
-    let _ = file.read_to_string(&mut contents);
-    let initial_data: Vec<char> = contents.trim_end().chars().collect();
-
-    let collapsed_data = collapse(initial_data.clone());
-
-    println!("Part 1: {}", collapsed_data.len());
-
-    let min_len = (97u8..=122) // 'a' to 'z'
+fn shortest_without_one_unit(initial_data: &[char]) -> usize {
+    (97u8..=122) // 'a' to 'z'
         .map(|exclude| {
             let filtered_data = initial_data
                 .iter()
                 .filter(|&c| c.to_lowercase().next().unwrap() != exclude.into())
                 .cloned();
             collapse(filtered_data).len()
-        }).min()
-        .unwrap();
+        })
+        .min()
+        .unwrap()
+}
+
+pub struct Day5;
+
+impl Solution for Day5 {
+    type Input = Vec<char>;
 
-    println!("Part 2: {}", min_len);
+    fn parse(&self, contents: &str) -> Result<Vec<char>, ParseError> {
+        Ok(contents.trim_end().chars().collect())
+    }
+
+    fn part1(&self, initial_data: &Vec<char>) -> Result<Answer, SolveError> {
+        Ok(collapse(initial_data.iter().cloned()).len().into())
+    }
+
+    fn part2(&self, initial_data: &Vec<char>) -> Result<Answer, SolveError> {
+        Ok(shortest_without_one_unit(initial_data).into())
+    }
 }
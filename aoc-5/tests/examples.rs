@@ -0,0 +1,23 @@
+use aoc_5::Day5;
+use aoc_common::{Answer, Solution};
+
+fn solve(input: &str) -> (Answer, Answer) {
+    let polymer = Day5.parse(input).unwrap();
+    (Day5.part1(&polymer).unwrap(), Day5.part2(&polymer).unwrap())
+}
+
+#[test]
+fn reactions() {
+    assert_eq!(solve("aA\n").0, Answer::Number(0));
+    assert_eq!(solve("abBA\n").0, Answer::Number(0));
+    assert_eq!(solve("abAB\n").0, Answer::Number(4));
+    assert_eq!(solve("aabAAB\n").0, Answer::Number(6));
+}
+
+#[test]
+fn example() {
+    assert_eq!(
+        solve("dabAcCaCBAcCcaDA\n"),
+        (Answer::Number(10), Answer::Number(4))
+    );
+}
@@ -1,12 +1,12 @@
-use nom::types::CompleteStr;
-use nom::*;
+use aoc_common::parsers::{bare_num, parse_lines, token, ParseResult};
+use aoc_common::{Answer, ParamError, Params, ParseError, Solution, SolveError};
+use nom::sequence::separated_pair;
+use nom::Parser;
 use std::collections::{HashMap, HashSet};
-use std::fs::File;
-use std::io::Read;
 use std::mem;
 
 #[derive(Clone, Copy, Debug, Hash, PartialEq, Eq)]
-struct Point {
+pub struct Point {
     pub x: isize,
     pub y: isize,
 }
@@ -45,15 +45,11 @@ fn distance(p1: Point, p2: Point) -> usize {
     ((p1.x - p2.x).abs() + (p1.y - p2.y).abs()) as usize
 }
 
-named!(point <CompleteStr, Point>, do_parse!(
-    x: map!(digit, |n| n.parse().unwrap()) >>
-    tag!(", ") >>
-    y: map!(digit, |n| n.parse().unwrap()) >>
-    line_ending >>
-    (Point { x, y })
-));
-
-named!(parse <CompleteStr, Vec<Point>>, many1!(point));
+fn point(input: &str) -> ParseResult<'_, Point> {
+    separated_pair(bare_num, token(", "), bare_num)
+        .map(|(x, y)| Point { x, y })
+        .parse(input)
+}
 
 type CenterId = u8;
 
@@ -63,13 +59,15 @@ enum Area {
 }
 
 struct Canvas {
+    bound: isize,
     points: HashMap<Point, CenterId>,
     owners: HashMap<CenterId, Area>,
 }
 
 impl Canvas {
-    fn new() -> Self {
+    fn new(bound: isize) -> Self {
         Self {
+            bound,
             points: HashMap::new(),
             owners: HashMap::new(),
         }
@@ -81,9 +79,9 @@ impl Canvas {
         points_queue.insert(center);
 
         'outer: while !points_queue.is_empty() {
-            let queue = mem::replace(&mut points_queue, HashSet::new());
+            let queue = mem::take(&mut points_queue);
             for point in queue {
-                if point.x < 0 || point.y < 0 || point.x > 500 || point.y > 500 {
+                if point.x < 0 || point.y < 0 || point.x > self.bound || point.y > self.bound {
                     let _ = self.owners.insert(center_id, Area::Infinite);
                     break 'outer;
                 }
@@ -95,7 +93,7 @@ impl Canvas {
                 {
                     v.push(point);
                 }
-                for new_point in vec![point.left(), point.right(), point.up(), point.down()] {
+                for new_point in [point.left(), point.right(), point.up(), point.down()] {
                     if self.points.contains_key(&new_point) {
                         continue;
                     }
@@ -123,38 +121,70 @@ impl Canvas {
 fn is_closest(point: Point, centers: &[Point], center_id: CenterId) -> bool {
     let dist = distance(point, centers[center_id as usize]);
     !centers
-        .into_iter()
+        .iter()
         .enumerate()
         .filter(|&(i, _)| i != center_id as usize)
         .any(|(_, p)| distance(point, *p) <= dist)
 }
 
-fn main() {
-    let mut file = File::open("input").unwrap();
-    let mut input = String::new();
-    let _ = file.read_to_string(&mut input);
-
-    let points = parse(CompleteStr(&input)).unwrap().1;
-
-    let mut canvas = Canvas::new();
+fn largest_finite_area(points: &[Point], bound: isize) -> usize {
+    let mut canvas = Canvas::new(bound);
 
     for point_id in 0..points.len() {
-        println!("Analysing #{}", point_id);
-        canvas.update_for_point(point_id as CenterId, &points);
+        canvas.update_for_point(point_id as CenterId, points);
     }
 
-    println!("Part 1: {}", canvas.max_size());
+    canvas.max_size()
+}
 
+fn safe_region_size(points: &[Point], bound: isize, max_distance: usize) -> usize {
     let mut area = 0;
-    for x in 0..500 {
-        for y in 0..500 {
+    for x in 0..bound {
+        for y in 0..bound {
             let point = Point { x, y };
             let sum_distances = points.iter().map(|p| distance(*p, point)).sum::<usize>();
-            if sum_distances < 10000 {
+            if sum_distances < max_distance {
                 area += 1;
             }
         }
     }
 
-    println!("Part 2: {}", area);
+    area
+}
+
+pub struct Day6 {
+    /// Areas reaching past `0..=bound` in either coordinate count as infinite.
+    pub bound: isize,
+    /// The total distance to all points that a safe location has to stay under.
+    pub max_distance: usize,
+}
+
+impl Default for Day6 {
+    fn default() -> Self {
+        Self {
+            bound: 500,
+            max_distance: 10000,
+        }
+    }
+}
+
+impl Solution for Day6 {
+    type Input = Vec<Point>;
+
+    fn configure(&mut self, params: &mut Params) -> Result<(), ParamError> {
+        params.update("bound", &mut self.bound)?;
+        params.update("max-distance", &mut self.max_distance)
+    }
+
+    fn parse(&self, input: &str) -> Result<Vec<Point>, ParseError> {
+        parse_lines(input, point)
+    }
+
+    fn part1(&self, points: &Vec<Point>) -> Result<Answer, SolveError> {
+        Ok(largest_finite_area(points, self.bound).into())
+    }
+
+    fn part2(&self, points: &Vec<Point>) -> Result<Answer, SolveError> {
+        Ok(safe_region_size(points, self.bound, self.max_distance).into())
+    }
 }
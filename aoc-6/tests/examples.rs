@@ -0,0 +1,16 @@
+use aoc_6::Day6;
+use aoc_common::{Answer, Solution};
+
+const EXAMPLE: &str = "1, 1\n1, 6\n8, 3\n3, 4\n5, 5\n8, 9\n";
+
+#[test]
+fn example() {
+    let day = Day6 {
+        max_distance: 32,
+        ..Day6::default()
+    };
+    let points = day.parse(EXAMPLE).unwrap();
+    assert_eq!(points.len(), 6);
+    assert_eq!(day.part1(&points).unwrap(), Answer::Number(17));
+    assert_eq!(day.part2(&points).unwrap(), Answer::Number(16));
+}
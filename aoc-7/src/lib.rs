@@ -1,26 +1,37 @@
-use nom::types::CompleteStr;
-use nom::*;
+use aoc_common::parsers::{expecting, parse_lines, token, ParseResult};
+use aoc_common::{Answer, Expected, ParamError, Params, ParseError, Solution, SolveError};
+use nom::character::complete::satisfy;
+use nom::sequence::{delimited, preceded};
+use nom::Parser;
 use std::collections::{HashMap, HashSet};
-use std::fs::File;
-use std::io::Read;
 
 #[derive(Clone, Copy, Debug)]
-struct Edge {
+pub struct Edge {
     start: char,
     end: char,
 }
 
-named!(edge <CompleteStr, Edge>, do_parse!(
-    tag!("Step ") >>
-    start: map!(take!(1), |s| s.chars().next().unwrap()) >>
-    tag!(" must be finished before step ") >>
-    end: map!(take!(1), |s| s.chars().next().unwrap()) >>
-    tag!(" can begin.") >>
-    line_ending >>
-    (Edge { start, end })
-));
+/// A step is named after an uppercase letter, which gives how long it takes.
+fn step(input: &str) -> ParseResult<'_, char> {
+    expecting(
+        Expected::Item("a step letter A-Z"),
+        satisfy(|c| c.is_ascii_uppercase()),
+    )
+    .parse(input)
+}
 
-named!(parse <CompleteStr, Vec<Edge>>, many1!(edge));
+fn edge(input: &str) -> ParseResult<'_, Edge> {
+    (
+        preceded(token("Step "), step),
+        delimited(
+            token(" must be finished before step "),
+            step,
+            token(" can begin."),
+        ),
+    )
+        .map(|(start, end)| Edge { start, end })
+        .parse(input)
+}
 
 #[derive(Clone, Debug)]
 struct Dependencies {
@@ -35,13 +46,8 @@ impl Dependencies {
     }
 
     fn insert(&mut self, edge: Edge) {
-        self.deps
-            .entry(edge.end)
-            .or_insert_with(HashSet::new)
-            .insert(edge.start);
-        if !self.deps.contains_key(&edge.start) {
-            self.deps.insert(edge.start, HashSet::new());
-        }
+        self.deps.entry(edge.end).or_default().insert(edge.start);
+        self.deps.entry(edge.start).or_default();
     }
 
     fn satisfy_dep(&mut self, dep: char) {
@@ -79,10 +85,7 @@ enum Worker {
 
 impl Worker {
     fn is_free(&self) -> bool {
-        match *self {
-            Worker::Free => true,
-            _ => false,
-        }
+        matches!(*self, Worker::Free)
     }
 
     fn finish(&self) -> Option<usize> {
@@ -94,14 +97,16 @@ impl Worker {
 }
 
 struct Scheduler {
-    workers: [Worker; 5],
+    workers: Vec<Worker>,
+    base_duration: usize,
     step: usize,
 }
 
 impl Scheduler {
-    fn new() -> Self {
+    fn new(workers: usize, base_duration: usize) -> Self {
         Self {
-            workers: [Worker::Free; 5],
+            workers: vec![Worker::Free; workers],
+            base_duration,
             step: 0,
         }
     }
@@ -144,7 +149,7 @@ impl Scheduler {
 
     fn schedule_task(&mut self, task: char) {
         let first_free = self.first_free_worker().unwrap();
-        let end_step = self.step + duration(task);
+        let end_step = self.step + self.base_duration + letter_number(task);
         assert!(self.workers[first_free].is_free());
         self.workers[first_free] = Worker::Working(task, end_step);
     }
@@ -157,36 +162,42 @@ impl Scheduler {
             .max()
             .unwrap();
         self.step = last_finish;
-        self.workers = [Worker::Free; 5];
+        for worker in &mut self.workers {
+            *worker = Worker::Free;
+        }
     }
 }
 
-fn duration(task: char) -> usize {
-    let mut buf = [0u8];
-    task.encode_utf8(&mut buf);
-    buf[0] as usize - 4
+/// 1 for A, 2 for B and so on.
+fn letter_number(task: char) -> usize {
+    task as usize - 'A' as usize + 1
 }
 
-fn main() {
-    let mut file = File::open("input").unwrap();
-    let mut input = String::new();
-    let _ = file.read_to_string(&mut input);
-
-    let edges = parse(CompleteStr(&input)).unwrap().1;
-
+fn dependencies(edges: &[Edge]) -> Dependencies {
     let mut dependencies = Dependencies::new();
     for edge in edges {
-        dependencies.insert(edge);
+        dependencies.insert(*edge);
     }
+    dependencies
+}
 
-    print!("Part 1: ");
+fn order(edges: &[Edge]) -> String {
+    let mut dependencies = dependencies(edges);
+    let mut order = String::new();
+    while let Some(step) = dependencies.next_step() {
+        order.push(step);
+        dependencies.satisfy_dep(step);
+    }
+    order
+}
 
-    let mut scheduler = Scheduler::new();
+fn completion_time(edges: &[Edge], workers: usize, base_duration: usize) -> usize {
+    let mut dependencies = dependencies(edges);
+    let mut scheduler = Scheduler::new(workers, base_duration);
     while !dependencies.is_empty() {
         loop {
             let next_step = dependencies.next_step();
             if let Some(next_task) = next_step {
-                print!("{}", next_task);
                 if !scheduler.can_schedule_task() {
                     let finished = scheduler.finish_task();
                     dependencies.satisfy_dep(finished);
@@ -199,9 +210,50 @@ fn main() {
             }
         }
     }
-    println!();
-
     scheduler.finish_all();
 
-    println!("Part 2: {}", scheduler.step);
+    scheduler.step()
+}
+
+pub struct Day7 {
+    pub workers: usize,
+    /// How long every step takes on top of its letter's number.
+    pub base_duration: usize,
+}
+
+impl Default for Day7 {
+    fn default() -> Self {
+        Self {
+            workers: 5,
+            base_duration: 60,
+        }
+    }
+}
+
+impl Solution for Day7 {
+    type Input = Vec<Edge>;
+
+    fn configure(&mut self, params: &mut Params) -> Result<(), ParamError> {
+        params.update("workers", &mut self.workers)?;
+        params.update("base-duration", &mut self.base_duration)?;
+        if self.workers == 0 {
+            return Err(ParamError::Invalid {
+                name: "workers".to_owned(),
+                value: self.workers.to_string(),
+            });
+        }
+        Ok(())
+    }
+
+    fn parse(&self, input: &str) -> Result<Vec<Edge>, ParseError> {
+        parse_lines(input, edge)
+    }
+
+    fn part1(&self, edges: &Vec<Edge>) -> Result<Answer, SolveError> {
+        Ok(order(edges).into())
+    }
+
+    fn part2(&self, edges: &Vec<Edge>) -> Result<Answer, SolveError> {
+        Ok(completion_time(edges, self.workers, self.base_duration).into())
+    }
 }
@@ -0,0 +1,62 @@
+use aoc_7::Day7;
+use aoc_common::{Answer, Expected, ParamError, Params, Solution};
+
+const EXAMPLE: &str = "\
+Step C must be finished before step A can begin.
+Step C must be finished before step F can begin.
+Step A must be finished before step B can begin.
+Step A must be finished before step D can begin.
+Step B must be finished before step E can begin.
+Step D must be finished before step E can begin.
+Step F must be finished before step E can begin.
+";
+
+#[test]
+fn example() {
+    let day = Day7 {
+        workers: 2,
+        base_duration: 0,
+    };
+    let edges = day.parse(EXAMPLE).unwrap();
+    assert_eq!(day.part1(&edges).unwrap(), Answer::from("CABDFE"));
+    assert_eq!(day.part2(&edges).unwrap(), Answer::Number(15));
+}
+
+#[test]
+fn order_ignores_workers() {
+    let edges = Day7::default().parse(EXAMPLE).unwrap();
+    assert_eq!(
+        Day7::default().part1(&edges).unwrap(),
+        Answer::from("CABDFE")
+    );
+    assert_eq!(Day7::default().part2(&edges).unwrap(), Answer::Number(253));
+}
+
+#[test]
+fn no_workers() {
+    let mut params = Params::new();
+    params.set("workers", "0");
+    assert_eq!(
+        Day7::default().configure(&mut params),
+        Err(ParamError::Invalid {
+            name: "workers".to_owned(),
+            value: "0".to_owned(),
+        })
+    );
+}
+
+#[test]
+fn only_uppercase_steps() {
+    for input in [
+        "Step a must be finished before step B can begin.\n",
+        "Step \u{c9} must be finished before step B can begin.\n",
+    ] {
+        let err = Day7::default().parse(input).unwrap_err();
+        assert_eq!((err.line, err.column), (1, 6));
+        assert_eq!(err.expected, Expected::Item("a step letter A-Z"));
+    }
+    let err = Day7::default()
+        .parse("Step A must be finished before step 7 can begin.\n")
+        .unwrap_err();
+    assert_eq!((err.line, err.column), (1, 37));
+}
@@ -1,28 +1,29 @@
-use nom::types::CompleteStr;
-use nom::*;
+use aoc_common::parsers::{number, parse_all, whitespace, ParseResult};
+use aoc_common::{Answer, ParseError, Solution, SolveError};
+use nom::multi::count;
+use nom::sequence::preceded;
+use nom::Parser;
 use std::collections::VecDeque;
 use std::fs::File;
-use std::io::{Read, Write};
+use std::io::{self, Write};
+use std::path::Path;
 
 #[derive(Clone, Debug)]
-struct TreeNode {
+pub struct TreeNode {
     children: Vec<TreeNode>,
     metadata: Vec<usize>,
 }
 
-named!(number <CompleteStr, usize>, do_parse!(
-    num: map!(digit, |x| x.parse().unwrap()) >>
-    multispace >>
-    (num)
-));
+fn node(input: &str) -> ParseResult<'_, TreeNode> {
+    let (input, (num_children, num_metadata)) = (number, number).parse(input)?;
+    (count(node, num_children), count(number, num_metadata))
+        .map(|(children, metadata)| TreeNode { children, metadata })
+        .parse(input)
+}
 
-named!(parse <CompleteStr, TreeNode>, do_parse!(
-    num_children: number >>
-    num_metadata: number >>
-    children: count!(parse, num_children) >>
-    metadata: count!(number, num_metadata) >>
-    (TreeNode { children, metadata })
-));
+fn parse(input: &str) -> ParseResult<'_, TreeNode> {
+    preceded(whitespace, node).parse(input)
+}
 
 impl TreeNode {
     fn sum_all_metadata(&self) -> usize {
@@ -48,8 +49,9 @@ impl TreeNode {
     }
 }
 
-fn dump(node: &TreeNode) {
-    let mut file = File::create("graph.dot").unwrap();
+/// Writes the tree out as a Graphviz graph, for debugging.
+pub fn dump<P: AsRef<Path>>(node: &TreeNode, path: P) -> io::Result<()> {
+    let mut file = File::create(path)?;
     let mut data = String::new();
     data.push_str("digraph aoc8 {\n");
 
@@ -72,19 +74,23 @@ fn dump(node: &TreeNode) {
 
     data.push_str("}\n");
 
-    let _ = file.write_all(data.as_bytes());
+    file.write_all(data.as_bytes())
 }
 
-fn main() {
-    let mut file = File::open("input").unwrap();
-    let mut data = String::new();
-    let _ = file.read_to_string(&mut data);
-    data.trim_right();
+pub struct Day8;
 
-    let data = parse(CompleteStr(&data)).unwrap().1;
+impl Solution for Day8 {
+    type Input = TreeNode;
 
-    println!("Part 1: {}", data.sum_all_metadata());
-    println!("Part 2: {}", data.value());
+    fn parse(&self, contents: &str) -> Result<TreeNode, ParseError> {
+        parse_all(contents, parse)
+    }
 
-    dump(&data);
+    fn part1(&self, tree: &TreeNode) -> Result<Answer, SolveError> {
+        Ok(tree.sum_all_metadata().into())
+    }
+
+    fn part2(&self, tree: &TreeNode) -> Result<Answer, SolveError> {
+        Ok(tree.value().into())
+    }
 }
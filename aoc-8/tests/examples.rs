@@ -0,0 +1,34 @@
+use aoc_8::{dump, Day8};
+use aoc_common::{Answer, Day, Solution, SolveError};
+use std::env;
+use std::fs;
+use std::process;
+
+#[test]
+fn example() {
+    let tree = Day8.parse("2 3 0 3 10 11 12 1 1 0 1 99 2 1 1 2\n").unwrap();
+    assert_eq!(Day8.part1(&tree).unwrap(), Answer::Number(138));
+    assert_eq!(Day8.part2(&tree).unwrap(), Answer::Number(66));
+}
+
+#[test]
+fn dump_graph() {
+    let tree = Day8.parse("2 3 0 3 10 11 12 1 1 0 1 99 2 1 1 2\n").unwrap();
+    let path = env::temp_dir().join(format!("aoc8-{}.dot", process::id()));
+    dump(&tree, &path).unwrap();
+    let graph = fs::read_to_string(&path).unwrap();
+    fs::remove_file(&path).unwrap();
+    assert!(graph.starts_with("digraph aoc8 {\n"));
+    assert!(graph.contains("1 -> 2\n1 -> 3\n"));
+    assert!(graph.contains("3 -> 4\n"));
+
+    let missing = env::temp_dir().join("aoc8-missing-dir").join("tree.dot");
+    assert!(dump(&tree, missing).is_err());
+}
+
+#[test]
+fn unknown_part() {
+    let solved = Day8.solve("0 1 7\n", &[2, 3]).unwrap();
+    assert_eq!(solved[0].answer, Ok(Answer::Number(7)));
+    assert_eq!(solved[1].answer, Err(SolveError::new("there is no part 3")));
+}
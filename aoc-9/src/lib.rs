@@ -1,5 +1,4 @@
-const N_PLAYERS: usize = 477;
-
+use aoc_common::{Answer, ParamError, Params, ParseError, Solution, SolveError};
 use std::collections::HashMap;
 
 struct Marble {
@@ -10,12 +9,12 @@ struct Marble {
 struct GameState {
     current_marble: usize,
     marbles: HashMap<usize, Marble>,
-    points: [usize; N_PLAYERS],
+    points: Vec<usize>,
     current_player: usize,
 }
 
 impl GameState {
-    fn new(max: usize) -> Self {
+    fn new(players: usize, max: usize) -> Self {
         let mut marbles = HashMap::with_capacity(max + 1);
         let _ = marbles.insert(
             0,
@@ -27,7 +26,7 @@ impl GameState {
         Self {
             current_marble: 0,
             marbles,
-            points: [0; N_PLAYERS],
+            points: vec![0; players],
             current_player: 0,
         }
     }
@@ -71,7 +70,7 @@ impl GameState {
     }
 
     fn place_marble(&mut self, marble: usize) {
-        if marble % 23 == 0 {
+        if marble.is_multiple_of(23) {
             self.points[self.current_player] += marble;
             self.move_counterclockwise(7);
             self.points[self.current_player] += self.current_marble;
@@ -80,7 +79,7 @@ impl GameState {
             self.move_clockwise(1);
             self.insert_marble_after_current(marble);
         }
-        self.current_player = (self.current_player + 1) % N_PLAYERS;
+        self.current_player = (self.current_player + 1) % self.points.len();
     }
 
     fn max_score(&self) -> usize {
@@ -88,8 +87,8 @@ impl GameState {
     }
 }
 
-fn game_with_max_marble(max: usize) -> usize {
-    let mut state = GameState::new(max);
+fn game_with_max_marble(players: usize, max: usize) -> usize {
+    let mut state = GameState::new(players, max);
 
     for marble in 1..=max {
         state.place_marble(marble);
@@ -98,7 +97,57 @@ fn game_with_max_marble(max: usize) -> usize {
     state.max_score()
 }
 
-fn main() {
-    println!("Part 1: {}", game_with_max_marble(70851));
-    println!("Part 2: {}", game_with_max_marble(7085100));
+pub struct Day9 {
+    pub players: usize,
+    /// The points of the last marble played in part 1.
+    pub last_marble: usize,
+    /// How many times more marbles part 2 plays.
+    pub part2_factor: usize,
+}
+
+impl Default for Day9 {
+    fn default() -> Self {
+        Self {
+            players: 477,
+            last_marble: 70851,
+            part2_factor: 100,
+        }
+    }
+}
+
+impl Solution for Day9 {
+    type Input = ();
+
+    fn configure(&mut self, params: &mut Params) -> Result<(), ParamError> {
+        params.update("players", &mut self.players)?;
+        params.update("last-marble", &mut self.last_marble)?;
+        params.update("part2-factor", &mut self.part2_factor)?;
+        if self.players == 0 {
+            return Err(ParamError::Invalid {
+                name: "players".to_owned(),
+                value: self.players.to_string(),
+            });
+        }
+        Ok(())
+    }
+
+    fn uses_input(&self) -> bool {
+        false
+    }
+
+    fn parse(&self, _input: &str) -> Result<(), ParseError> {
+        Ok(())
+    }
+
+    fn part1(&self, _input: &()) -> Result<Answer, SolveError> {
+        Ok(game_with_max_marble(self.players, self.last_marble).into())
+    }
+
+    fn part2(&self, _input: &()) -> Result<Answer, SolveError> {
+        let last_marble = self
+            .last_marble
+            .checked_mul(self.part2_factor)
+            .ok_or_else(|| SolveError::new("the last marble of part 2 is too large"))?;
+        Ok(game_with_max_marble(self.players, last_marble).into())
+    }
 }
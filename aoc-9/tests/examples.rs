@@ -0,0 +1,54 @@
+use aoc_9::Day9;
+use aoc_common::{Answer, ParamError, Params, Solution};
+
+fn high_score(players: usize, last_marble: usize) -> Answer {
+    let day = Day9 {
+        players,
+        last_marble,
+        ..Day9::default()
+    };
+    day.part1(&day.parse("").unwrap()).unwrap()
+}
+
+#[test]
+fn examples() {
+    assert_eq!(high_score(9, 25), Answer::Number(32));
+    assert_eq!(high_score(10, 1618), Answer::Number(8317));
+    assert_eq!(high_score(13, 7999), Answer::Number(146373));
+    assert_eq!(high_score(17, 1104), Answer::Number(2764));
+    assert_eq!(high_score(21, 6111), Answer::Number(54718));
+    assert_eq!(high_score(30, 5807), Answer::Number(37305));
+}
+
+#[test]
+fn longer_game() {
+    let day = Day9 {
+        players: 9,
+        last_marble: 25,
+        part2_factor: 2,
+    };
+    // The same game as `high_score(9, 50)`.
+    assert_eq!(day.part2(&()).unwrap(), high_score(9, 50));
+}
+
+#[test]
+fn no_players() {
+    let mut params = Params::new();
+    params.set("players", "0");
+    assert_eq!(
+        Day9::default().configure(&mut params),
+        Err(ParamError::Invalid {
+            name: "players".to_owned(),
+            value: "0".to_owned(),
+        })
+    );
+}
+
+#[test]
+fn too_many_marbles() {
+    let day = Day9 {
+        last_marble: usize::MAX / 2,
+        ..Day9::default()
+    };
+    assert!(day.part2(&()).is_err());
+}
@@ -0,0 +1,54 @@
+use crate::answer::Answer;
+use crate::error::{ParamError, ParseError, SolveError};
+use crate::params::Params;
+use crate::solution::Solution;
+use std::time::{Duration, Instant};
+
+/// The answer to one part of a puzzle, or why there is none, along with how long it took to find.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Solved {
+    pub part: u8,
+    pub answer: Result<Answer, SolveError>,
+    /// The time spent solving the part, not counting parsing the input.
+    pub elapsed: Duration,
+}
+
+/// A type-erased `Solution`, so that all days can be driven the same way.
+pub trait Day {
+    fn uses_input(&self) -> bool;
+
+    fn configure(&mut self, params: &mut Params) -> Result<(), ParamError>;
+
+    /// Parses the input once and solves the given parts of the puzzle.
+    fn solve(&self, input: &str, parts: &[u8]) -> Result<Vec<Solved>, ParseError>;
+}
+
+impl<S: Solution> Day for S {
+    fn uses_input(&self) -> bool {
+        Solution::uses_input(self)
+    }
+
+    fn configure(&mut self, params: &mut Params) -> Result<(), ParamError> {
+        Solution::configure(self, params)
+    }
+
+    fn solve(&self, input: &str, parts: &[u8]) -> Result<Vec<Solved>, ParseError> {
+        let input = self.parse(input)?;
+        Ok(parts
+            .iter()
+            .map(|&part| {
+                let start = Instant::now();
+                let answer = match part {
+                    1 => self.part1(&input),
+                    2 => self.part2(&input),
+                    _ => Err(SolveError::new(format!("there is no part {}", part))),
+                };
+                Solved {
+                    part,
+                    answer,
+                    elapsed: start.elapsed(),
+                }
+            })
+            .collect())
+    }
+}
@@ -0,0 +1,120 @@
+use std::error::Error;
+use std::fmt;
+
+/// What a parser was looking for when it failed.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Expected {
+    /// A literal piece of text, like `" @ "`.
+    Token(&'static str),
+    /// Something described in words, like "a number".
+    Item(&'static str),
+    /// A number small enough to fit in the named type.
+    NumberFitting(&'static str),
+    EndOfLine,
+    EndOfInput,
+}
+
+impl fmt::Display for Expected {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match *self {
+            Expected::Token(token) => write!(f, "{:?}", token),
+            Expected::Item(description) => write!(f, "{}", description),
+            Expected::NumberFitting(ty) => write!(f, "a number that fits in {}", ty),
+            Expected::EndOfLine => write!(f, "end of line"),
+            Expected::EndOfInput => write!(f, "end of input"),
+        }
+    }
+}
+
+/// An error encountered while parsing a puzzle input.
+///
+/// Lines and columns are counted from 1, columns in characters.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ParseError {
+    pub line: usize,
+    pub column: usize,
+    /// The rest of the offending line, starting at the error.
+    pub found: String,
+    pub expected: Expected,
+}
+
+impl ParseError {
+    /// Creates an error at the given byte offset into `input`.
+    pub fn at(input: &str, offset: usize, expected: Expected) -> Self {
+        let before = &input[..offset];
+        let line_start = before.rfind('\n').map_or(0, |i| i + 1);
+        let found = input[offset..].lines().next().unwrap_or("");
+        Self {
+            line: before.matches('\n').count() + 1,
+            column: before[line_start..].chars().count() + 1,
+            found: found.to_owned(),
+            expected,
+        }
+    }
+}
+
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "line {}, column {}: expected {}, found ",
+            self.line, self.column, self.expected
+        )?;
+        if self.found.is_empty() {
+            write!(f, "end of line")
+        } else {
+            write!(f, "{:?}", self.found)
+        }
+    }
+}
+
+impl Error for ParseError {}
+
+/// An error preventing a part of a puzzle from being solved, usually because the input has no
+/// answer.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct SolveError {
+    pub reason: String,
+}
+
+impl SolveError {
+    pub fn new<R: Into<String>>(reason: R) -> Self {
+        Self {
+            reason: reason.into(),
+        }
+    }
+}
+
+impl fmt::Display for SolveError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.reason)
+    }
+}
+
+impl Error for SolveError {}
+
+/// An error in the puzzle parameters given on the command line.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum ParamError {
+    /// Not of the form `name=value`.
+    Malformed(String),
+    Invalid {
+        name: String,
+        value: String,
+    },
+    Unknown(String),
+}
+
+impl fmt::Display for ParamError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            ParamError::Malformed(arg) => write!(f, "expected `name=value`, found {:?}", arg),
+            ParamError::Invalid { name, value } => {
+                write!(f, "invalid value {:?} for parameter `{}`", value, name)
+            }
+            ParamError::Unknown(name) => write!(f, "unknown parameter `{}`", name),
+        }
+    }
+}
+
+impl Error for ParamError {}
@@ -0,0 +1,57 @@
+use std::ffi::OsStr;
+use std::fmt;
+use std::fs::File;
+use std::io::{self, BufRead, BufReader, Read};
+use std::path::{Path, PathBuf};
+
+/// Where to read a puzzle input from.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum InputSource {
+    Stdin,
+    File(PathBuf),
+}
+
+impl InputSource {
+    /// The default input of the given day within `inputs_dir`, like `inputs/day07.txt`.
+    pub fn for_day<P: AsRef<Path>>(inputs_dir: P, day: u8) -> Self {
+        InputSource::File(inputs_dir.as_ref().join(format!("day{:02}.txt", day)))
+    }
+
+    /// Reads the whole input.
+    pub fn read(&self) -> io::Result<String> {
+        let mut contents = String::new();
+        match self {
+            InputSource::Stdin => io::stdin().read_to_string(&mut contents)?,
+            InputSource::File(path) => File::open(path)?.read_to_string(&mut contents)?,
+        };
+        Ok(contents)
+    }
+
+    /// Opens the input for reading it bit by bit, as it arrives.
+    pub fn open(&self) -> io::Result<Box<dyn BufRead>> {
+        Ok(match self {
+            InputSource::Stdin => Box::new(io::stdin().lock()),
+            InputSource::File(path) => Box::new(BufReader::new(File::open(path)?)),
+        })
+    }
+}
+
+/// `-` stands for the standard input, anything else is a path.
+impl<'a> From<&'a OsStr> for InputSource {
+    fn from(arg: &'a OsStr) -> Self {
+        if arg == "-" {
+            InputSource::Stdin
+        } else {
+            InputSource::File(arg.into())
+        }
+    }
+}
+
+impl fmt::Display for InputSource {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            InputSource::Stdin => write!(f, "standard input"),
+            InputSource::File(path) => write!(f, "{}", path.display()),
+        }
+    }
+}
@@ -0,0 +1,20 @@
+pub mod answer;
+pub mod day;
+pub mod error;
+pub mod input;
+pub mod params;
+pub mod parsers;
+pub mod report;
+#[cfg(feature = "rng")]
+pub mod rng;
+pub mod solution;
+
+pub use crate::answer::Answer;
+pub use crate::day::{Day, Solved};
+pub use crate::error::{Expected, ParamError, ParseError, SolveError};
+pub use crate::input::InputSource;
+pub use crate::params::Params;
+pub use crate::report::{report, report_json};
+#[cfg(feature = "rng")]
+pub use crate::rng::Rng;
+pub use crate::solution::Solution;
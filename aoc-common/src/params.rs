@@ -0,0 +1,50 @@
+use crate::error::ParamError;
+use std::collections::BTreeMap;
+use std::str::FromStr;
+
+/// Named puzzle parameters, like `workers=2`, overriding a solution's defaults.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct Params {
+    values: BTreeMap<String, String>,
+}
+
+impl Params {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn set<N: Into<String>, V: Into<String>>(&mut self, name: N, value: V) {
+        let _ = self.values.insert(name.into(), value.into());
+    }
+
+    /// Parses a `name=value` pair and adds it to the parameters.
+    pub fn add(&mut self, assignment: &str) -> Result<(), ParamError> {
+        let mut split = assignment.splitn(2, '=');
+        match (split.next(), split.next()) {
+            (Some(name), Some(value)) if !name.is_empty() => {
+                self.set(name, value);
+                Ok(())
+            }
+            _ => Err(ParamError::Malformed(assignment.to_owned())),
+        }
+    }
+
+    /// Overwrites `target` with the parameter `name`, if it was given, and marks it as used.
+    pub fn update<T: FromStr>(&mut self, name: &str, target: &mut T) -> Result<(), ParamError> {
+        if let Some(value) = self.values.remove(name) {
+            *target = value.parse().map_err(|_| ParamError::Invalid {
+                name: name.to_owned(),
+                value,
+            })?;
+        }
+        Ok(())
+    }
+
+    /// Fails if any of the parameters hasn't been used.
+    pub fn finish(self) -> Result<(), ParamError> {
+        match self.values.into_iter().next() {
+            Some((name, _)) => Err(ParamError::Unknown(name)),
+            None => Ok(()),
+        }
+    }
+}
@@ -0,0 +1,179 @@
+use crate::error::{Expected, ParseError};
+use nom::branch::alt;
+use nom::bytes::complete::tag;
+use nom::character::complete::{char, digit1, multispace0, space0};
+use nom::combinator::{opt, value};
+use nom::error::ErrorKind;
+use nom::sequence::terminated;
+use nom::{Err, IResult, Parser};
+use std::any;
+use std::str::FromStr;
+
+/// The error of a single parser: the position where it failed and what it expected there.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Mismatch<'a> {
+    pub at: &'a str,
+    pub expected: Expected,
+}
+
+impl<'a> nom::error::ParseError<&'a str> for Mismatch<'a> {
+    fn from_error_kind(at: &'a str, _kind: ErrorKind) -> Self {
+        Mismatch {
+            at,
+            expected: Expected::Item("valid input"),
+        }
+    }
+
+    /// Keeps the innermost error, which knows best what was expected.
+    fn append(_at: &'a str, _kind: ErrorKind, other: Self) -> Self {
+        other
+    }
+}
+
+/// The result of a parser that reports what it expected when it fails.
+pub type ParseResult<'a, T> = IResult<&'a str, T, Mismatch<'a>>;
+
+/// Fails at the given position, expecting `expected` there.
+pub fn fail<T>(at: &str, expected: Expected) -> ParseResult<'_, T> {
+    Err(Err::Error(Mismatch { at, expected }))
+}
+
+/// Runs `parser`, but reports a failure as expecting `expected` where it started.
+pub fn expecting<'a, P>(
+    expected: Expected,
+    mut parser: P,
+) -> impl Parser<&'a str, Output = P::Output, Error = Mismatch<'a>>
+where
+    P: Parser<&'a str, Error = Mismatch<'a>>,
+{
+    move |input: &'a str| {
+        parser.parse(input).map_err(|err| {
+            err.map(|_| Mismatch {
+                at: input,
+                expected,
+            })
+        })
+    }
+}
+
+/// Exactly the given text.
+pub fn token<'a>(
+    token: &'static str,
+) -> impl Parser<&'a str, Output = &'a str, Error = Mismatch<'a>> {
+    expecting(Expected::Token(token), tag(token))
+}
+
+/// Zero or more whitespace characters.
+pub fn whitespace(input: &str) -> ParseResult<'_, &str> {
+    multispace0(input)
+}
+
+/// The number written from the start of `text` up to `rest`, failing at `digits` if it doesn't fit
+/// in a `T`. Its sign is converted along with its digits, so that the minimum of `T` fits.
+fn convert<'a, T: FromStr>(text: &'a str, digits: &'a str, rest: &'a str) -> ParseResult<'a, T> {
+    match text[..text.len() - rest.len()].parse() {
+        Ok(num) => Ok((rest, num)),
+        Err(_) => fail(digits, Expected::NumberFitting(any::type_name::<T>())),
+    }
+}
+
+fn digits(input: &str) -> ParseResult<'_, &str> {
+    expecting(Expected::Item("a number"), digit1).parse(input)
+}
+
+/// An unsigned decimal number.
+pub fn bare_num<T: FromStr>(input: &str) -> ParseResult<'_, T> {
+    let (rest, _) = digits(input)?;
+    convert(input, input, rest)
+}
+
+/// An unsigned decimal number, optionally followed by whitespace.
+pub fn number<T: FromStr>(input: &str) -> ParseResult<'_, T> {
+    terminated(bare_num, whitespace).parse(input)
+}
+
+/// `+` or `-`, returned as `true` for a negative sign.
+pub fn sign(input: &str) -> ParseResult<'_, bool> {
+    expecting(
+        Expected::Item("a sign"),
+        alt((value(false, char('+')), value(true, char('-')))),
+    )
+    .parse(input)
+}
+
+/// A number with a mandatory sign, like `+7` or `-12`.
+pub fn signed<T: FromStr>(input: &str) -> ParseResult<'_, T> {
+    let (unsigned, _) = sign(input)?;
+    let (rest, _) = digits(unsigned)?;
+    convert(input, unsigned, rest)
+}
+
+/// A number padded with spaces to a common width, like ` 7`, `-12` or `  3`.
+pub fn padded_signed<T: FromStr>(input: &str) -> ParseResult<'_, T> {
+    let (number, _) = space0(input)?;
+    let (unsigned, _) = opt(char('-')).parse(number)?;
+    let (rest, _) = digits(unsigned)?;
+    convert(number, unsigned, rest)
+}
+
+/// The offset in `input` of `rest`, which is a suffix of `parsed`, which in turn is a part of
+/// `input`.
+fn offset(input: &str, parsed: &str, rest: &str) -> usize {
+    let start = parsed.as_ptr() as usize - input.as_ptr() as usize;
+    start + parsed.len() - rest.len()
+}
+
+fn error_in(input: &str, parsed: &str, err: Err<Mismatch>) -> ParseError {
+    match err {
+        Err::Error(mismatch) | Err::Failure(mismatch) => {
+            ParseError::at(input, offset(input, parsed, mismatch.at), mismatch.expected)
+        }
+        Err::Incomplete(_) => ParseError::at(
+            input,
+            offset(input, parsed, ""),
+            Expected::Item("more input"),
+        ),
+    }
+}
+
+/// Parses the whole input with `parser`, rejecting anything left over.
+pub fn parse_all<'a, T, P>(input: &'a str, mut parser: P) -> Result<T, ParseError>
+where
+    P: Parser<&'a str, Output = T, Error = Mismatch<'a>>,
+{
+    match parser.parse(input) {
+        Ok((rest, _)) if !rest.is_empty() => Err(ParseError::at(
+            input,
+            offset(input, input, rest),
+            Expected::EndOfInput,
+        )),
+        Ok((_, value)) => Ok(value),
+        Err(err) => Err(error_in(input, input, err)),
+    }
+}
+
+/// Parses every line of a non-empty input with `parser`, which has to consume the whole line.
+pub fn parse_lines<'a, T, P>(input: &'a str, mut parser: P) -> Result<Vec<T>, ParseError>
+where
+    P: Parser<&'a str, Output = T, Error = Mismatch<'a>>,
+{
+    if input.trim().is_empty() {
+        return Err(ParseError::at(
+            input,
+            0,
+            Expected::Item("at least one line"),
+        ));
+    }
+    input
+        .lines()
+        .map(|line| match parser.parse(line) {
+            Ok((rest, _)) if !rest.is_empty() => Err(ParseError::at(
+                input,
+                offset(input, line, rest),
+                Expected::EndOfLine,
+            )),
+            Ok((_, value)) => Ok(value),
+            Err(err) => Err(error_in(input, line, err)),
+        })
+        .collect()
+}
@@ -0,0 +1,31 @@
+use crate::answer::Answer;
+use serde::Serialize;
+use std::time::Duration;
+
+/// Prints the answer to the given part of a day's puzzle.
+pub fn report(day: u8, part: u8, answer: &Answer) {
+    match answer {
+        Answer::Text(s) if s.contains('\n') => println!("Day {} part {}:\n{}", day, part, s),
+        _ => println!("Day {} part {}: {}", day, part, answer),
+    }
+}
+
+#[derive(Serialize)]
+struct Record<'a> {
+    day: u8,
+    part: u8,
+    answer: &'a Answer,
+    elapsed_ms: f64,
+}
+
+/// Prints the answer to the given part of a day's puzzle, and the time it took to find, as a JSON
+/// object on a line of its own.
+pub fn report_json(day: u8, part: u8, answer: &Answer, elapsed: Duration) {
+    let record = Record {
+        day,
+        part,
+        answer,
+        elapsed_ms: elapsed.as_secs_f64() * 1000.0,
+    };
+    println!("{}", serde_json::to_string(&record).unwrap());
+}
@@ -0,0 +1,24 @@
+use crate::answer::Answer;
+use crate::error::{ParamError, ParseError, SolveError};
+use crate::params::Params;
+
+/// A day's puzzle, split into parsing the input and solving both parts.
+pub trait Solution {
+    type Input;
+
+    /// Whether the solution needs a puzzle input at all.
+    fn uses_input(&self) -> bool {
+        true
+    }
+
+    /// Takes the puzzle parameters this solution understands out of `params`.
+    fn configure(&mut self, _params: &mut Params) -> Result<(), ParamError> {
+        Ok(())
+    }
+
+    fn parse(&self, input: &str) -> Result<Self::Input, ParseError>;
+
+    fn part1(&self, input: &Self::Input) -> Result<Answer, SolveError>;
+
+    fn part2(&self, input: &Self::Input) -> Result<Answer, SolveError>;
+}
@@ -0,0 +1,70 @@
+//! Measures parsing and both parts of every day separately.
+//!
+//! The inputs are generated to be about the size and shape of real puzzle inputs, and are the
+//! same on every run, so the results can be compared over time.
+
+mod inputs;
+
+use aoc_common::Solution;
+use criterion::{criterion_group, criterion_main, BenchmarkId, Criterion};
+use std::hint::black_box;
+
+fn bench_day<S: Solution>(c: &mut Criterion, day: u8, solution: &S, input: &str) {
+    let mut group = c.benchmark_group(format!("day{:02}", day));
+    // Some of the parts take seconds, and the default 100 samples would take ages.
+    group.sample_size(10);
+    group.bench_function("parse", |b| {
+        b.iter(|| solution.parse(black_box(input)).unwrap())
+    });
+    let parsed = solution.parse(input).unwrap();
+    group.bench_function("part1", |b| b.iter(|| solution.part1(black_box(&parsed))));
+    group.bench_function("part2", |b| b.iter(|| solution.part2(black_box(&parsed))));
+    group.finish();
+}
+
+fn days(c: &mut Criterion) {
+    bench_day(c, 1, &aoc_1::Day1::default(), &inputs::day1());
+    bench_day(c, 2, &aoc_2::Day2, &inputs::day2());
+    bench_day(c, 3, &aoc_3::Day3, &inputs::day3());
+    bench_day(c, 4, &aoc_4::Day4::default(), &inputs::day4());
+    bench_day(c, 5, &aoc_5::Day5, &inputs::day5());
+    bench_day(c, 6, &aoc_6::Day6::default(), &inputs::day6());
+    bench_day(c, 7, &aoc_7::Day7::default(), &inputs::day7());
+    bench_day(c, 8, &aoc_8::Day8, &inputs::day8());
+    bench_day(c, 9, &aoc_9::Day9::default(), "");
+    bench_day(c, 10, &aoc_10::Day10, &inputs::day10());
+}
+
+/// Part 2 of day 1 on inputs far larger than the real one, to see how it scales.
+fn day1_scaling(c: &mut Criterion) {
+    let mut group = c.benchmark_group("day01-scaling");
+    group.sample_size(10);
+    for &lines in &[1_000_000, 2_000_000, 4_000_000] {
+        let day = aoc_1::Day1::default();
+        let changes = day.parse(&inputs::day1_climbing(lines)).unwrap();
+        group.bench_with_input(BenchmarkId::new("part2", lines), &changes, |b, changes| {
+            b.iter(|| day.part2(changes))
+        });
+    }
+    group.finish();
+}
+
+/// Day 2 on inventories far larger than the real one.
+fn day2_scaling(c: &mut Criterion) {
+    let mut group = c.benchmark_group("day02-scaling");
+    group.sample_size(10);
+    let ids = aoc_2::Day2.parse(&inputs::day2_ids(1_000_000)).unwrap();
+    group.bench_with_input(BenchmarkId::new("part1", ids.len()), &ids, |b, ids| {
+        b.iter(|| aoc_2::Day2.part1(ids))
+    });
+    for &count in &[10_000, 50_000] {
+        let ids = aoc_2::Day2.parse(&inputs::day2_ids(count)).unwrap();
+        group.bench_with_input(BenchmarkId::new("part2", count), &ids, |b, ids| {
+            b.iter(|| aoc_2::Day2.part2(ids))
+        });
+    }
+    group.finish();
+}
+
+criterion_group!(benches, days, day1_scaling, day2_scaling);
+criterion_main!(benches);
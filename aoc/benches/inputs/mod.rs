@@ -0,0 +1,191 @@
+//! Generators of puzzle inputs resembling the real ones.
+
+use aoc_common::Rng;
+use std::fmt::Write;
+
+/// About a thousand frequency changes drifting slowly upwards.
+pub fn day1() -> String {
+    let mut rng = Rng::new(1);
+    let mut changes: Vec<i64> = (0..1000)
+        .map(|_| {
+            let change = rng.range(1, 20);
+            if rng.range(0, 2) == 0 {
+                change
+            } else {
+                -change
+            }
+        })
+        .collect();
+    let drift: i64 = changes.iter().sum();
+    changes.push(50 - drift);
+    changes
+        .iter()
+        .map(|change| format!("{:+}\n", change))
+        .collect()
+}
+
+/// `lines` frequency changes that climb steadily and then fall back to a small drift, so that no
+/// frequency repeats before the later passes.
+pub fn day1_climbing(lines: usize) -> String {
+    let mut rng = Rng::new(11);
+    let mut changes: Vec<i64> = (1..lines).map(|_| rng.range(1, 20)).collect();
+    let climb: i64 = changes.iter().sum();
+    changes.push(7 - climb);
+    changes
+        .iter()
+        .map(|change| format!("{:+}\n", change))
+        .collect()
+}
+
+/// 250 box IDs, two of which differ by one letter.
+pub fn day2() -> String {
+    day2_ids(250)
+}
+
+/// `count` box IDs, two of which differ by one letter.
+pub fn day2_ids(count: usize) -> String {
+    let mut rng = Rng::new(2);
+    let mut ids: Vec<String> = (0..count)
+        .map(|_| (0..26).map(|_| rng.letter()).collect())
+        .collect();
+    let mut similar: Vec<char> = ids[count * 2 / 5].chars().collect();
+    similar[13] = if similar[13] == 'z' { 'a' } else { 'z' };
+    ids[count * 4 / 5] = similar.into_iter().collect();
+    ids.iter().map(|id| format!("{}\n", id)).collect()
+}
+
+/// 1300 claims on the 1000x1000 fabric, one of which overlaps no other.
+pub fn day3() -> String {
+    let mut rng = Rng::new(3);
+    let mut claims = String::new();
+    for id in 1..=1300 {
+        let (x, y) = if id == 650 {
+            (975, 975)
+        } else {
+            (rng.range(0, 940), rng.range(0, 940))
+        };
+        let (w, h) = (rng.range(10, 30), rng.range(10, 30));
+        writeln!(claims, "#{} @ {},{}: {}x{}", id, x, y, w, h).unwrap();
+    }
+    claims
+}
+
+/// A shuffled log of 300 nights guarded by 20 guards.
+pub fn day4() -> String {
+    let mut rng = Rng::new(4);
+    let mut lines = Vec::new();
+    for night in 0..300 {
+        let (month, day) = (night / 28 + 1, night % 28 + 1);
+        let stamp = |minute| format!("[1518-{:02}-{:02} 00:{:02}]", month, day, minute);
+        let guard = rng.range(1, 21) * 97;
+        lines.push(format!("{} Guard #{} begins shift", stamp(0), guard));
+        let mut minute = rng.range(1, 10);
+        for _ in 0..rng.range(0, 4) {
+            let asleep = minute + rng.range(1, 5);
+            let awake = asleep + rng.range(1, 12);
+            if awake >= 60 {
+                break;
+            }
+            lines.push(format!("{} falls asleep", stamp(asleep)));
+            lines.push(format!("{} wakes up", stamp(awake)));
+            minute = awake;
+        }
+    }
+    rng.shuffle(&mut lines);
+    lines.iter().map(|line| format!("{}\n", line)).collect()
+}
+
+/// A polymer of 50000 units.
+pub fn day5() -> String {
+    let mut rng = Rng::new(5);
+    let mut polymer: String = (0..50000)
+        .map(|_| {
+            let unit = rng.letter();
+            if rng.range(0, 2) == 0 {
+                unit
+            } else {
+                unit.to_ascii_uppercase()
+            }
+        })
+        .collect();
+    polymer.push('\n');
+    polymer
+}
+
+/// 50 coordinates in the middle of the grid.
+pub fn day6() -> String {
+    let mut rng = Rng::new(6);
+    (0..50)
+        .map(|_| format!("{}, {}\n", rng.range(40, 360), rng.range(40, 360)))
+        .collect()
+}
+
+/// About a hundred dependencies between all 26 steps.
+pub fn day7() -> String {
+    let mut rng = Rng::new(7);
+    let mut steps: Vec<char> = ('A'..='Z').collect();
+    rng.shuffle(&mut steps);
+    let mut edges = Vec::new();
+    for (i, &before) in steps.iter().enumerate() {
+        for &after in &steps[i + 1..] {
+            if rng.range(0, 3) == 0 {
+                edges.push(format!(
+                    "Step {} must be finished before step {} can begin.\n",
+                    before, after
+                ));
+            }
+        }
+    }
+    rng.shuffle(&mut edges);
+    edges.concat()
+}
+
+fn tree_node(rng: &mut Rng, depth: u32, numbers: &mut Vec<i64>) {
+    let children = if depth == 0 { 0 } else { rng.range(2, 6) };
+    let metadata = rng.range(1, 4);
+    numbers.push(children);
+    numbers.push(metadata);
+    for i in 0..children {
+        // Apart from the first one, half of the children are leaves, which keeps the tree from
+        // growing too large.
+        let child_depth = if i == 0 || rng.range(0, 2) == 0 {
+            depth - 1
+        } else {
+            0
+        };
+        tree_node(rng, child_depth, numbers);
+    }
+    for _ in 0..metadata {
+        numbers.push(rng.range(1, 10));
+    }
+}
+
+/// A license tree of several thousand nodes.
+pub fn day8() -> String {
+    let mut rng = Rng::new(8);
+    let mut numbers = Vec::new();
+    tree_node(&mut rng, 9, &mut numbers);
+    let numbers: Vec<String> = numbers.iter().map(i64::to_string).collect();
+    numbers.join(" ") + "\n"
+}
+
+/// 350 lights that line up after about 10000 seconds.
+pub fn day10() -> String {
+    let mut rng = Rng::new(10);
+    let seconds = 10000 + rng.range(0, 500);
+    let mut lights = String::new();
+    for _ in 0..350 {
+        let (x, y) = (rng.range(0, 62), rng.range(0, 10));
+        let (dx, dy) = (rng.range(-5, 6), rng.range(-5, 6));
+        writeln!(
+            lights,
+            "position=<{:6}, {:6}> velocity=<{:2}, {:2}>",
+            x - dx * seconds,
+            y - dy * seconds,
+            dx,
+            dy
+        )
+        .unwrap();
+    }
+    lights
+}
@@ -0,0 +1,21 @@
+use aoc_common::Day;
+
+pub const NUM_DAYS: u8 = 10;
+
+/// Returns the solver for the given day.
+pub fn solver(day: u8) -> Option<Box<dyn Day>> {
+    let solver: Box<dyn Day> = match day {
+        1 => Box::new(aoc_1::Day1::default()),
+        2 => Box::new(aoc_2::Day2),
+        3 => Box::new(aoc_3::Day3),
+        4 => Box::new(aoc_4::Day4::default()),
+        5 => Box::new(aoc_5::Day5),
+        6 => Box::new(aoc_6::Day6::default()),
+        7 => Box::new(aoc_7::Day7::default()),
+        8 => Box::new(aoc_8::Day8),
+        9 => Box::new(aoc_9::Day9::default()),
+        10 => Box::new(aoc_10::Day10),
+        _ => return None,
+    };
+    Some(solver)
+}
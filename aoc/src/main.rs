@@ -0,0 +1,271 @@
+use aoc::days;
+use aoc_1::{calibrate, Day1};
+use aoc_3::{Day3, Heatmap};
+use aoc_8::{dump, Day8};
+use aoc_common::{report, report_json, Answer, InputSource, Params, Solution};
+use clap::{Parser, Subcommand, ValueEnum};
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::process;
+use std::time::{Duration, Instant};
+
+#[derive(Parser)]
+#[command(name = "aoc", about = "Advent of Code 2018 solutions")]
+struct Cli {
+    #[command(subcommand)]
+    command: Command,
+}
+
+#[derive(Subcommand)]
+enum Command {
+    /// Solves the puzzles of one or all days
+    Run {
+        /// The day to solve; all days are solved if omitted
+        #[arg(long, value_parser = clap::value_parser!(u8).range(1..=days::NUM_DAYS as i64))]
+        day: Option<u8>,
+        /// The part to solve; both parts are solved if omitted
+        #[arg(long, value_parser = clap::value_parser!(u8).range(1..=2))]
+        part: Option<u8>,
+        /// The puzzle input, or `-` for the standard input; defaults to `dayNN.txt` in the
+        /// inputs directory
+        #[arg(long, requires = "day")]
+        input: Option<PathBuf>,
+        /// The directory holding the default inputs
+        #[arg(long, env = "AOC_INPUTS_DIR", default_value = "inputs")]
+        inputs_dir: PathBuf,
+        /// A puzzle parameter overriding the day's default, like `workers=2`; may be repeated
+        #[arg(
+            long = "param",
+            short = 'p',
+            value_name = "NAME=VALUE",
+            requires = "day"
+        )]
+        params: Vec<String>,
+        /// How to print the answers
+        #[arg(long, value_enum, default_value_t = Format::Text)]
+        format: Format,
+        /// Solve while reading the input, reporting answers as soon as they're known; only day 1
+        /// supports this
+        #[arg(long, requires = "day")]
+        stream: bool,
+        /// Draw a heatmap of the claims to this file instead of solving, as a PNG image if it
+        /// ends in `.png` and a PPM one otherwise; only day 3 supports this
+        #[arg(long, value_name = "PATH", requires = "day", conflicts_with = "stream")]
+        heatmap: Option<PathBuf>,
+        /// Write the tree of nodes to this file as a Graphviz graph instead of solving; only day 8
+        /// supports this
+        #[arg(
+            long,
+            value_name = "PATH",
+            requires = "day",
+            conflicts_with_all = ["stream", "heatmap"]
+        )]
+        tree: Option<PathBuf>,
+    },
+}
+
+#[derive(Clone, Copy, ValueEnum)]
+enum Format {
+    /// One `Day D part P: answer` line per part
+    Text,
+    /// One JSON object per part, with the day, the part, the answer and the time it took
+    Json,
+}
+
+fn print(format: Format, day: u8, part: u8, answer: &Answer, elapsed: Duration) {
+    match format {
+        Format::Text => report(day, part, answer),
+        Format::Json => report_json(day, part, answer, elapsed),
+    }
+}
+
+fn run_day(
+    day: u8,
+    parts: &[u8],
+    input: Option<InputSource>,
+    inputs_dir: &Path,
+    mut params: Params,
+    format: Format,
+) -> Result<(), String> {
+    let mut solver = days::solver(day).ok_or_else(|| format!("no solver for day {}", day))?;
+    solver
+        .configure(&mut params)
+        .and_then(|()| params.finish())
+        .map_err(|e| format!("day {}: {}", day, e))?;
+    let contents = if solver.uses_input() {
+        let source = input.unwrap_or_else(|| InputSource::for_day(inputs_dir, day));
+        source
+            .read()
+            .map_err(|e| format!("couldn't read {}: {}", source, e))?
+    } else {
+        String::new()
+    };
+    let solved = solver
+        .solve(&contents, parts)
+        .map_err(|e| format!("day {}: {}", day, e))?;
+    for solved in &solved {
+        let answer = solved
+            .answer
+            .as_ref()
+            .map_err(|e| format!("day {} part {}: {}", day, solved.part, e))?;
+        print(format, day, solved.part, answer, solved.elapsed);
+    }
+    Ok(())
+}
+
+/// Calibrates the device of day 1 from changes as they're read, so that inputs being written or
+/// too large to hold can be solved too.
+fn stream_day(
+    day: u8,
+    parts: &[u8],
+    input: Option<InputSource>,
+    inputs_dir: &Path,
+    mut params: Params,
+    format: Format,
+) -> Result<(), String> {
+    if day != 1 {
+        return Err(format!("day {} can't be solved from a stream", day));
+    }
+    let mut solver = Day1::default();
+    solver
+        .configure(&mut params)
+        .and_then(|()| params.finish())
+        .map_err(|e| format!("day {}: {}", day, e))?;
+    let source = input.unwrap_or_else(|| InputSource::for_day(inputs_dir, day));
+    let reader = source
+        .open()
+        .map_err(|e| format!("couldn't read {}: {}", source, e))?;
+
+    let start = Instant::now();
+    let mut calibration = solver.calibration(parts.contains(&2));
+    calibrate(reader, &mut calibration, |freq| {
+        print(format, day, 2, &freq.into(), start.elapsed())
+    })
+    .map_err(|e| format!("day {}: {}", day, e))?;
+    if parts.contains(&1) {
+        print(
+            format,
+            day,
+            1,
+            &calibration.frequency().into(),
+            start.elapsed(),
+        );
+    }
+    if parts.contains(&2) && calibration.first_repeated().is_none() {
+        return Err(format!(
+            "day {} part 2: no frequency was reached twice in {}",
+            day, source
+        ));
+    }
+    Ok(())
+}
+
+/// Draws how contested the fabric of day 3 is, to look at the claims rather than count them.
+fn draw_heatmap(
+    day: u8,
+    input: Option<InputSource>,
+    inputs_dir: &Path,
+    path: &Path,
+) -> Result<(), String> {
+    if day != 3 {
+        return Err(format!("day {} has no heatmap", day));
+    }
+    let source = input.unwrap_or_else(|| InputSource::for_day(inputs_dir, day));
+    let contents = source
+        .read()
+        .map_err(|e| format!("couldn't read {}: {}", source, e))?;
+    let claims = Day3
+        .parse(&contents)
+        .map_err(|e| format!("day {}: {}", day, e))?;
+    let heatmap = Heatmap::new(&claims).map_err(|e| format!("day {}: {}", day, e))?;
+
+    let mut image = Vec::new();
+    if path.extension().is_some_and(|ext| ext == "png") {
+        write_png(&heatmap, &mut image)?;
+    } else {
+        heatmap
+            .write_ppm(&mut image)
+            .expect("writing to memory doesn't fail");
+    }
+    fs::write(path, image).map_err(|e| format!("couldn't write {}: {}", path.display(), e))
+}
+
+/// Writes the tree of day 8 as a graph, to look at its shape.
+fn dump_tree(
+    day: u8,
+    input: Option<InputSource>,
+    inputs_dir: &Path,
+    path: &Path,
+) -> Result<(), String> {
+    if day != 8 {
+        return Err(format!("day {} has no tree", day));
+    }
+    let source = input.unwrap_or_else(|| InputSource::for_day(inputs_dir, day));
+    let contents = source
+        .read()
+        .map_err(|e| format!("couldn't read {}: {}", source, e))?;
+    let tree = Day8
+        .parse(&contents)
+        .map_err(|e| format!("day {}: {}", day, e))?;
+    dump(&tree, path).map_err(|e| format!("couldn't write {}: {}", path.display(), e))
+}
+
+#[cfg(feature = "png")]
+fn write_png(heatmap: &Heatmap, out: &mut Vec<u8>) -> Result<(), String> {
+    heatmap
+        .write_png(out)
+        .map_err(|e| format!("couldn't encode the heatmap: {}", e))
+}
+
+#[cfg(not(feature = "png"))]
+fn write_png(_: &Heatmap, _: &mut Vec<u8>) -> Result<(), String> {
+    Err("PNG images need the `png` feature".to_owned())
+}
+
+fn main() {
+    let Command::Run {
+        day,
+        part,
+        input,
+        inputs_dir,
+        params: assignments,
+        format,
+        stream,
+        heatmap,
+        tree,
+    } = Cli::parse().command;
+
+    let mut params = Params::new();
+    for assignment in &assignments {
+        if let Err(err) = params.add(assignment) {
+            eprintln!("error: {}", err);
+            process::exit(1);
+        }
+    }
+
+    let parts = match part {
+        Some(part) => vec![part],
+        None => vec![1, 2],
+    };
+    let result = match day {
+        Some(day) => {
+            let input = input.map(|path| InputSource::from(path.as_os_str()));
+            if let Some(path) = &heatmap {
+                draw_heatmap(day, input, &inputs_dir, path)
+            } else if let Some(path) = &tree {
+                dump_tree(day, input, &inputs_dir, path)
+            } else if stream {
+                stream_day(day, &parts, input, &inputs_dir, params, format)
+            } else {
+                run_day(day, &parts, input, &inputs_dir, params, format)
+            }
+        }
+        None => (1..=days::NUM_DAYS)
+            .try_for_each(|day| run_day(day, &parts, None, &inputs_dir, Params::new(), format)),
+    };
+
+    if let Err(err) = result {
+        eprintln!("error: {}", err);
+        process::exit(1);
+    }
+}
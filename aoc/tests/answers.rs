@@ -0,0 +1,150 @@
+//! Checks the solutions against the answers recorded for the examples of the puzzle statements,
+//! kept in `tests/examples`, and for our own puzzle inputs.
+//!
+//! Our inputs are looked up like the runner does, in `AOC_INPUTS_DIR` or `inputs` at the root of
+//! the workspace. The answers are recorded next to the inputs in `answers.txt`, one `DAY PART
+//! ANSWER` line per part, with the answer as displayed by the runner. An answer spanning several
+//! lines, like the message of day 10, is written on the lines following `DAY PART`, each starting
+//! with `| `. Trailing whitespace is ignored on every line of an answer, and so are empty lines and
+//! lines starting with `#` between answers. A day is only skipped when both its input and its
+//! answers are missing.
+
+use aoc::days;
+use aoc_common::InputSource;
+use std::collections::BTreeMap;
+use std::env;
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+
+/// The answers of the days solved without an input, which don't depend on the inputs directory.
+const BUILT_IN_ANSWERS: &[(u8, u8, &str)] = &[(9, 1, "374690"), (9, 2, "3009951158")];
+
+fn inputs_dir() -> PathBuf {
+    match env::var_os("AOC_INPUTS_DIR") {
+        Some(dir) => dir.into(),
+        None => PathBuf::from(env!("CARGO_MANIFEST_DIR")).join("../inputs"),
+    }
+}
+
+fn read_answers(path: &Path) -> BTreeMap<(u8, u8), String> {
+    let mut answers: BTreeMap<(u8, u8), String> = BUILT_IN_ANSWERS
+        .iter()
+        .map(|&(day, part, answer)| ((day, part), answer.to_owned()))
+        .collect();
+    let contents = match fs::read_to_string(path) {
+        Ok(contents) => contents,
+        Err(err) if err.kind() == io::ErrorKind::NotFound => return answers,
+        Err(err) => panic!("couldn't read {}: {}", path.display(), err),
+    };
+    let mut lines = contents.lines().enumerate().peekable();
+    while let Some((number, line)) = lines.next() {
+        let line = line.trim();
+        if line.is_empty() || line.starts_with('#') {
+            continue;
+        }
+        let mut fields = line.splitn(3, char::is_whitespace);
+        let key = match (fields.next(), fields.next()) {
+            (Some(day), Some(part)) => day.parse().and_then(|day| Ok((day, part.parse()?))).ok(),
+            _ => None,
+        };
+        let answer = match fields.next() {
+            Some(answer) => answer.trim().to_owned(),
+            None => {
+                let mut rows = Vec::new();
+                while let Some((_, row)) = lines.next_if(|(_, row)| row.starts_with('|')) {
+                    let row = &row[1..];
+                    rows.push(row.strip_prefix(' ').unwrap_or(row));
+                }
+                rows.join("\n")
+            }
+        };
+        match key {
+            Some(key) if !answer.is_empty() => {
+                let _ = answers.insert(key, normalise(&answer));
+            }
+            _ => panic!(
+                "{}:{}: expected `DAY PART ANSWER`, found {:?}",
+                path.display(),
+                number + 1,
+                line
+            ),
+        }
+    }
+    answers
+}
+
+/// The answer without trailing whitespace on its lines, which editors tend to strip.
+fn normalise(answer: &str) -> String {
+    let lines: Vec<_> = answer.lines().map(str::trim_end).collect();
+    lines.join("\n")
+}
+
+/// Solves every day from the inputs in `inputs_dir`, describing every answer not matching the one
+/// recorded there.
+fn check_answers(inputs_dir: &Path) -> Vec<String> {
+    let answers = read_answers(&inputs_dir.join("answers.txt"));
+    let mut failures = Vec::new();
+    for day in 1..=days::NUM_DAYS {
+        let solver = days::solver(day).unwrap();
+        let source = InputSource::for_day(inputs_dir, day);
+        let contents = if solver.uses_input() {
+            source.read().ok()
+        } else {
+            Some(String::new())
+        };
+        let expected: Vec<_> = (1..=2).map(|part| answers.get(&(day, part))).collect();
+        let contents = match contents {
+            Some(contents) => contents,
+            None if expected.iter().all(Option::is_none) => {
+                eprintln!("skipping day {}: no input nor answers", day);
+                continue;
+            }
+            None => {
+                failures.push(format!(
+                    "day {}: answers recorded but {} is missing",
+                    day, source
+                ));
+                continue;
+            }
+        };
+        for (part, expected) in (1..=2).zip(expected) {
+            let expected = match expected {
+                Some(expected) => expected,
+                None => {
+                    failures.push(format!("day {} part {}: no answer recorded", day, part));
+                    continue;
+                }
+            };
+            let solved = match solver.solve(&contents, &[part]) {
+                Ok(solved) => solved,
+                Err(err) => {
+                    failures.push(format!("day {}: {}", day, err));
+                    break;
+                }
+            };
+            match &solved[0].answer {
+                Ok(answer) if normalise(&answer.to_string()) == *expected => {}
+                Ok(answer) => failures.push(format!(
+                    "day {} part {}: expected {}, got {}",
+                    day, part, expected, answer
+                )),
+                Err(err) => failures.push(format!("day {} part {}: {}", day, part, err)),
+            }
+        }
+    }
+    failures
+}
+
+#[test]
+fn example_answers() {
+    let examples = Path::new(env!("CARGO_MANIFEST_DIR")).join("tests/examples");
+    let failures = check_answers(&examples);
+    assert!(failures.is_empty(), "{}", failures.join("\n"));
+}
+
+#[test]
+fn recorded_answers() {
+    let failures = check_answers(&inputs_dir());
+    assert!(failures.is_empty(), "{}", failures.join("\n"));
+}